@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use packed_char::{Contents, PackedChar, U22};
+
+const LEN: u32 = 4096;
+
+fn chars() -> Vec<Contents> {
+    (0..LEN)
+        .map(|i| Contents::Char(char::from_u32(i * 7 % 0xD800).unwrap()))
+        .collect()
+}
+
+fn u22s() -> Vec<Contents> {
+    (0..LEN)
+        .map(|i| Contents::U22(U22::from_u32(i * 1021 % U22::MAX).unwrap()))
+        .collect()
+}
+
+fn mixed() -> Vec<Contents> {
+    chars()
+        .into_iter()
+        .zip(u22s())
+        .enumerate()
+        .map(|(i, (c, u))| if i % 2 == 0 { c } else { u })
+        .collect()
+}
+
+fn single(c: &mut Criterion) {
+    let u22 = U22::from_u32(0x2A_AAAA).unwrap();
+    c.bench_function("from_u22", |b| {
+        b.iter(|| PackedChar::from_u22(black_box(u22)))
+    });
+    let packed = PackedChar::from_u22(u22);
+    c.bench_function("contents/u22", |b| b.iter(|| black_box(packed).contents()));
+    let packed = PackedChar::from_char('🫠');
+    c.bench_function("contents/char", |b| b.iter(|| black_box(packed).contents()));
+}
+
+fn bulk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bulk");
+    group.throughput(Throughput::Elements(LEN.into()));
+    for (name, input) in [("char", chars()), ("u22", u22s()), ("mixed", mixed())] {
+        group.bench_with_input(BenchmarkId::new("pack", name), &input, |b, input| {
+            b.iter(|| {
+                black_box(input)
+                    .iter()
+                    .map(|&contents| PackedChar::from(contents))
+                    .collect::<Vec<_>>()
+            })
+        });
+        let packed: Vec<_> = input.iter().map(|&c| PackedChar::from(c)).collect();
+        group.bench_with_input(BenchmarkId::new("unpack", name), &packed, |b, packed| {
+            b.iter(|| {
+                black_box(packed)
+                    .iter()
+                    .map(|p| p.contents())
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, single, bulk);
+criterion_main!(benches);
@@ -0,0 +1,160 @@
+use crate::PackedChar;
+use core::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// A fixed-capacity sequence of up to `N` [`PackedChar`]s stored inline, without allocating.
+///
+/// Requires the `array` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{PackedChar, PackedCharArray};
+/// let mut array = PackedCharArray::<2>::new();
+/// assert_eq!(array.push(PackedChar::from('a')), Ok(()));
+/// assert_eq!(array.push(PackedChar::from('b')), Ok(()));
+/// assert_eq!(array.push(PackedChar::from('c')), Err(PackedChar::from('c')));
+/// assert_eq!(array.as_slice(), [PackedChar::from('a'), PackedChar::from('b')]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct PackedCharArray<const N: usize> {
+    items: [PackedChar; N],
+    len: usize,
+}
+
+impl<const N: usize> PackedCharArray<N> {
+    /// Creates an empty array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedCharArray;
+    /// const EMPTY: PackedCharArray<4> = PackedCharArray::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            items: [PackedChar::from_char('\0'); N],
+            len: 0,
+        }
+    }
+
+    /// Appends an element, or returns it if the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharArray};
+    /// let mut array = PackedCharArray::<1>::new();
+    /// let u22 = PackedChar::try_from(42).unwrap();
+    /// assert_eq!(array.push(u22), Ok(()));
+    /// assert_eq!(array.push(u22), Err(u22));
+    /// ```
+    pub fn push(&mut self, packed: PackedChar) -> Result<(), PackedChar> {
+        match self.items.get_mut(self.len) {
+            Some(slot) => {
+                *slot = packed;
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(packed),
+        }
+    }
+
+    /// Removes and returns the last element, or `None` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharArray};
+    /// let mut array = PackedCharArray::<1>::new();
+    /// array.push(PackedChar::from('a')).unwrap();
+    /// assert_eq!(array.pop(), Some(PackedChar::from('a')));
+    /// assert_eq!(array.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<PackedChar> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.items[self.len])
+    }
+
+    /// Gets the stored elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharArray};
+    /// let mut array = PackedCharArray::<4>::new();
+    /// array.push(PackedChar::from('a')).unwrap();
+    /// assert_eq!(array.as_slice(), [PackedChar::from('a')]);
+    /// ```
+    pub const fn as_slice(&self) -> &[PackedChar] {
+        self.items.split_at(self.len).0
+    }
+
+    /// Gets the number of stored elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharArray};
+    /// let mut array = PackedCharArray::<4>::new();
+    /// array.push(PackedChar::from('a')).unwrap();
+    /// assert_eq!(array.len(), 1);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the array has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedCharArray;
+    /// assert!(PackedCharArray::<4>::new().is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the maximum number of elements, `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedCharArray;
+    /// assert_eq!(PackedCharArray::<4>::new().capacity(), 4);
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for PackedCharArray<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compares the stored elements, ignoring unused capacity.
+impl<const N: usize> PartialEq for PackedCharArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for PackedCharArray<N> {}
+
+impl<const N: usize> Hash for PackedCharArray<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<const N: usize> Debug for PackedCharArray<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,139 @@
+use crate::U22;
+use core::fmt::{self, Display, Formatter};
+
+/// Assembles a [`U22`] from bit fields, from the most significant field to the least.
+///
+/// Errors are deferred until [`U22Builder::build`], so calls can be chained, including in `const`
+/// contexts.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{U22, U22Builder};
+/// const PACKED: U22 = match U22Builder::new()
+///     .push_bits(0b10101, 5)
+///     .push_bits(0b00011, 5)
+///     .push_bits(0xFFF, 12)
+///     .build()
+/// {
+///     Ok(u22) => u22,
+///     Err(_) => panic!(),
+/// };
+/// assert_eq!(PACKED.as_u32(), 0b10101_00011_111111111111);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U22Builder {
+    bits: u32,
+    width: u32,
+    error: Option<U22BuilderError>,
+}
+
+impl U22Builder {
+    /// Creates a builder with no fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22Builder};
+    /// assert_eq!(U22Builder::new().build(), Ok(U22::from(0u8)));
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            bits: 0,
+            width: 0,
+            error: None,
+        }
+    }
+
+    /// Appends a field `width` bits wide below the fields pushed so far.
+    ///
+    /// If `value` does not fit in `width` bits or the total width exceeds [`U22::BITS`], the
+    /// error is reported by [`U22Builder::build`]. Once an error occurs, later fields are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22Builder, U22BuilderError};
+    /// let u22 = U22Builder::new().push_bits(1, 1).push_bits(0, 3).build();
+    /// assert_eq!(u22.map(|u22| u22.as_u32()), Ok(0b1000));
+    /// let overflow = U22Builder::new().push_bits(4, 2).build();
+    /// assert_eq!(overflow, Err(U22BuilderError::FieldOverflow { value: 4, width: 2 }));
+    /// let too_wide = U22Builder::new().push_bits(0, 20).push_bits(0, 3).build();
+    /// assert_eq!(too_wide, Err(U22BuilderError::TooWide { width: 23 }));
+    /// ```
+    pub const fn push_bits(self, value: u32, width: u32) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        let total = self.width.saturating_add(width);
+        let error = if total > U22::BITS {
+            U22BuilderError::TooWide { width: total }
+        } else if value >> width != 0 {
+            U22BuilderError::FieldOverflow { value, width }
+        } else {
+            return Self {
+                bits: self.bits << width | value,
+                width: total,
+                error: None,
+            };
+        };
+        Self {
+            error: Some(error),
+            ..self
+        }
+    }
+
+    /// Gets the assembled value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`U22Builder::push_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22Builder;
+    /// let u22 = U22Builder::new().push_bits(0x3F, 6).push_bits(0xFFFF, 16).build();
+    /// assert_eq!(u22.map(|u22| u22.as_u32()), Ok(0x3F_FFFF));
+    /// ```
+    pub const fn build(self) -> Result<U22, U22BuilderError> {
+        match self.error {
+            // SAFETY: push_bits keeps the total width within 22 bits.
+            None => Ok(unsafe { U22::from_u32_unchecked(self.bits) }),
+            Some(error) => Err(error),
+        }
+    }
+}
+
+/// The error type returned by [`U22Builder::build`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum U22BuilderError {
+    /// The fields add up to more than [`U22::BITS`] bits.
+    TooWide {
+        /// The total width including the field that exceeded the limit.
+        width: u32,
+    },
+    /// A field value does not fit in its declared width.
+    FieldOverflow {
+        /// The value of the field.
+        value: u32,
+        /// The declared width of the field.
+        width: u32,
+    },
+}
+
+impl Display for U22BuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooWide { width } => write!(
+                f,
+                "fields are {width} bits wide, exceeding the {} bits of a U22",
+                U22::BITS,
+            ),
+            Self::FieldOverflow { value, width } => {
+                write!(f, "{value} does not fit in a {width}-bit field")
+            }
+        }
+    }
+}
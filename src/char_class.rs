@@ -0,0 +1,112 @@
+use crate::PackedChar;
+use core::ops::{BitOr, BitOrAssign};
+
+/// A set of character classes, used to filter which `char`s may be stored.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::CharClass;
+/// let classes = CharClass::of('\n');
+/// assert!(classes.contains(CharClass::CONTROL | CharClass::WHITESPACE));
+/// assert!(!classes.contains(CharClass::NONCHARACTER));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharClass(u8);
+
+impl CharClass {
+    /// Control characters, as determined by [`char::is_control`].
+    pub const CONTROL: Self = Self(1 << 0);
+    /// Whitespace characters, as determined by [`char::is_whitespace`].
+    pub const WHITESPACE: Self = Self(1 << 1);
+    /// Unicode noncharacters: U+FDD0 to U+FDEF and the last two code points of each plane.
+    pub const NONCHARACTER: Self = Self(1 << 2);
+
+    /// The set containing no classes.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// The set containing every class.
+    pub const fn all() -> Self {
+        Self::CONTROL
+            .union(Self::WHITESPACE)
+            .union(Self::NONCHARACTER)
+    }
+
+    /// The set of classes the given `char` belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::CharClass;
+    /// assert_eq!(CharClass::of('a'), CharClass::empty());
+    /// assert_eq!(CharClass::of(' '), CharClass::WHITESPACE);
+    /// assert_eq!(CharClass::of('\u{FFFF}'), CharClass::NONCHARACTER);
+    /// ```
+    pub fn of(c: char) -> Self {
+        let mut classes = Self::empty();
+        if c.is_control() {
+            classes |= Self::CONTROL;
+        }
+        if c.is_whitespace() {
+            classes |= Self::WHITESPACE;
+        }
+        if is_noncharacter(c) {
+            classes |= Self::NONCHARACTER;
+        }
+        classes
+    }
+
+    /// The classes in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Whether every class in `other` is also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for CharClass {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for CharClass {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+const fn is_noncharacter(c: char) -> bool {
+    let n = c as u32;
+    matches!(n, 0xFDD0..=0xFDEF) || n & 0xFFFE == 0xFFFE
+}
+
+impl PackedChar {
+    /// Creates a new value from the given `char` if every class it belongs to is allowed.
+    /// `char`s that belong to no class are always accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CharClass, PackedChar};
+    /// assert_eq!(PackedChar::from_char_filtered('a', CharClass::empty()), Some(PackedChar::from('a')));
+    /// assert_eq!(PackedChar::from_char_filtered('\0', CharClass::WHITESPACE), None);
+    /// assert_eq!(PackedChar::from_char_filtered(' ', CharClass::WHITESPACE), Some(PackedChar::from(' ')));
+    /// // Newline is both a control and a whitespace character
+    /// assert_eq!(PackedChar::from_char_filtered('\n', CharClass::WHITESPACE), None);
+    /// ```
+    pub fn from_char_filtered(c: char, allow: CharClass) -> Option<Self> {
+        if allow.contains(CharClass::of(c)) {
+            Some(Self::from_char(c))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! The checked [`U22`] operations as free functions.
+//!
+//! Each function forwards to the `checked_` method of the same name. Free functions are easier to
+//! pass around as `fn` pointers and to use in combinators.
+//!
+//! ```
+//! # use packed_char::{checked, U22};
+//! let values = [1u8, 2, 3].map(U22::from);
+//! let sum = values.into_iter().try_fold(U22::default(), checked::add);
+//! assert_eq!(sum, Some(U22::from(6u8)));
+//! ```
+
+use crate::U22;
+
+/// See [`U22::checked_add`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::add(U22::from(40u8), U22::from(2u8)), Some(U22::from(42u8)));
+/// ```
+pub const fn add(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_add(rhs)
+}
+
+/// See [`U22::checked_sub`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::sub(U22::from(0u8), U22::from(1u8)), None);
+/// ```
+pub const fn sub(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_sub(rhs)
+}
+
+/// See [`U22::checked_mul`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::mul(U22::from(21u8), U22::from(2u8)), Some(U22::from(42u8)));
+/// ```
+pub const fn mul(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_mul(rhs)
+}
+
+/// See [`U22::checked_add_u32`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::add_u32(U22::from(40u8), 2), Some(U22::from(42u8)));
+/// ```
+pub const fn add_u32(lhs: U22, rhs: u32) -> Option<U22> {
+    lhs.checked_add_u32(rhs)
+}
+
+/// See [`U22::checked_mul_u32`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::mul_u32(U22::from(21u8), 2), Some(U22::from(42u8)));
+/// ```
+pub const fn mul_u32(lhs: U22, rhs: u32) -> Option<U22> {
+    lhs.checked_mul_u32(rhs)
+}
+
+/// See [`U22::checked_div_euclid`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::div_euclid(U22::from(7u8), U22::from(0u8)), None);
+/// ```
+pub const fn div_euclid(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_div_euclid(rhs)
+}
+
+/// See [`U22::checked_rem_euclid`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::rem_euclid(U22::from(7u8), U22::from(2u8)), Some(U22::from(1u8)));
+/// ```
+pub const fn rem_euclid(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_rem_euclid(rhs)
+}
+
+/// See [`U22::checked_div_ceil`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::div_ceil(U22::from(7u8), U22::from(2u8)), Some(U22::from(4u8)));
+/// ```
+pub const fn div_ceil(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_div_ceil(rhs)
+}
+
+/// See [`U22::checked_div_rem`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// let (q, r) = checked::div_rem(U22::from(7u8), U22::from(2u8)).unwrap();
+/// assert_eq!((q.as_u32(), r.as_u32()), (3, 1));
+/// ```
+pub const fn div_rem(lhs: U22, rhs: U22) -> Option<(U22, U22)> {
+    lhs.checked_div_rem(rhs)
+}
+
+/// See [`U22::checked_next_multiple_of`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::next_multiple_of(U22::from(5u8), U22::from(4u8)), Some(U22::from(8u8)));
+/// ```
+pub const fn next_multiple_of(lhs: U22, rhs: U22) -> Option<U22> {
+    lhs.checked_next_multiple_of(rhs)
+}
+
+/// See [`U22::checked_pow`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::pow(U22::from(2u8), 22), None);
+/// ```
+pub const fn pow(base: U22, exp: u32) -> Option<U22> {
+    base.checked_pow(exp)
+}
+
+/// See [`U22::checked_add_signed`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::add_signed(U22::from(44u8), -2), Some(U22::from(42u8)));
+/// ```
+pub const fn add_signed(lhs: U22, delta: i32) -> Option<U22> {
+    lhs.checked_add_signed(delta)
+}
+
+/// See [`U22::checked_sub_signed`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::sub_signed(U22::from(40u8), -2), Some(U22::from(42u8)));
+/// ```
+pub const fn sub_signed(lhs: U22, delta: i32) -> Option<U22> {
+    lhs.checked_sub_signed(delta)
+}
+
+/// See [`U22::checked_ilog`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::ilog(U22::from(81u8), U22::from(3u8)), Some(4));
+/// ```
+pub const fn ilog(value: U22, base: U22) -> Option<u32> {
+    value.checked_ilog(base)
+}
+
+/// See [`U22::checked_ilog2`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::ilog2(U22::from(0u8)), None);
+/// ```
+pub const fn ilog2(value: U22) -> Option<u32> {
+    value.checked_ilog2()
+}
+
+/// See [`U22::checked_ilog10`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::ilog10(U22::from(100u8)), Some(2));
+/// ```
+pub const fn ilog10(value: U22) -> Option<u32> {
+    value.checked_ilog10()
+}
+
+/// See [`U22::checked_sum`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::sum(&[40u8, 2].map(U22::from)), Some(U22::from(42u8)));
+/// ```
+pub const fn sum(slice: &[U22]) -> Option<U22> {
+    U22::checked_sum(slice)
+}
+
+/// See [`U22::checked_product`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{checked, U22};
+/// assert_eq!(checked::product(&[21u8, 2].map(U22::from)), Some(U22::from(42u8)));
+/// ```
+pub const fn product(slice: &[U22]) -> Option<U22> {
+    U22::checked_product(slice)
+}
@@ -0,0 +1,191 @@
+use crate::{Contents, PackedChar, U22};
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+/// A growable sequence of [`PackedChar`]s stored in 3 bytes each rather than 4.
+///
+/// A `char` needs at most 21 bits and a [`U22`] needs 22, so either fits in 24 bits alongside a
+/// tag bit. Elements are decoded as they are accessed.
+///
+/// Requires the `compact` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{CompactPackedBuffer, PackedChar};
+/// let mut buffer = CompactPackedBuffer::new();
+/// buffer.push(PackedChar::from('a'));
+/// buffer.push(PackedChar::try_from(42).unwrap());
+/// assert_eq!(buffer.len(), 2);
+/// assert_eq!(buffer.get(1), Some(PackedChar::try_from(42).unwrap()));
+/// assert_eq!(buffer.get(2), None);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct CompactPackedBuffer(Vec<u8>);
+
+impl CompactPackedBuffer {
+    const ELEMENT_SIZE: usize = 3;
+    const U22_TAG: u32 = 1 << 23;
+
+    /// Creates a new, empty buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::CompactPackedBuffer;
+    /// let buffer = CompactPackedBuffer::new();
+    /// assert!(buffer.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Creates a new, empty buffer with space for at least `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::CompactPackedBuffer;
+    /// let buffer = CompactPackedBuffer::with_capacity(4);
+    /// assert!(buffer.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(
+            capacity.saturating_mul(Self::ELEMENT_SIZE),
+        ))
+    }
+
+    /// The number of elements in the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CompactPackedBuffer, PackedChar};
+    /// let buffer: CompactPackedBuffer = "abc".chars().map(PackedChar::from).collect();
+    /// assert_eq!(buffer.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len() / Self::ELEMENT_SIZE
+    }
+
+    /// Whether the buffer contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CompactPackedBuffer, PackedChar};
+    /// let mut buffer = CompactPackedBuffer::new();
+    /// assert!(buffer.is_empty());
+    /// buffer.push(PackedChar::from('a'));
+    /// assert!(!buffer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Appends an element to the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CompactPackedBuffer, PackedChar};
+    /// let mut buffer = CompactPackedBuffer::new();
+    /// buffer.push(PackedChar::from('a'));
+    /// assert_eq!(buffer.get(0), Some(PackedChar::from('a')));
+    /// ```
+    pub fn push(&mut self, packed: PackedChar) {
+        let encoded = match packed.contents() {
+            Contents::Char(c) => c as u32,
+            Contents::U22(u22) => u22.as_u32() | Self::U22_TAG,
+        };
+        let [a, b, c, _] = encoded.to_le_bytes();
+        self.0.extend_from_slice(&[a, b, c]);
+    }
+
+    /// Gets the element at the given index, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CompactPackedBuffer, PackedChar};
+    /// let u22 = PackedChar::try_from(42).unwrap();
+    /// let buffer: CompactPackedBuffer = [PackedChar::from('a'), u22].into_iter().collect();
+    /// assert_eq!(buffer.get(1), Some(u22));
+    /// assert_eq!(buffer.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<PackedChar> {
+        let start = index.checked_mul(Self::ELEMENT_SIZE)?;
+        let end = start.checked_add(Self::ELEMENT_SIZE)?;
+        let bytes = self.0.get(start..end)?;
+        let encoded = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+        let value = encoded & !Self::U22_TAG;
+        // SAFETY: Only valid chars and U22s are encoded by push.
+        let packed = unsafe {
+            if encoded & Self::U22_TAG == 0 {
+                PackedChar::from_char(char::from_u32_unchecked(value))
+            } else {
+                PackedChar::from_u22(U22::from_u32_unchecked(value))
+            }
+        };
+        Some(packed)
+    }
+
+    /// Iterates over the elements of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{CompactPackedBuffer, PackedChar};
+    /// let buffer: CompactPackedBuffer = "ab".chars().map(PackedChar::from).collect();
+    /// assert!(buffer.iter().eq("ab".chars().map(PackedChar::from)));
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = PackedChar> + '_ {
+        (0..self.len()).map(|i| self.get(i).expect("index is in bounds"))
+    }
+}
+
+impl Debug for CompactPackedBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<PackedChar> for CompactPackedBuffer {
+    fn extend<T: IntoIterator<Item = PackedChar>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        self.0
+            .reserve(iter.size_hint().0.saturating_mul(Self::ELEMENT_SIZE));
+        for packed in iter {
+            self.push(packed);
+        }
+    }
+}
+
+impl FromIterator<PackedChar> for CompactPackedBuffer {
+    fn from_iter<T: IntoIterator<Item = PackedChar>>(iter: T) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    fn compact_buffer_roundtrips() {
+        let packed: Vec<_> = testing::BOUNDARY_CHARS
+            .into_iter()
+            .map(PackedChar::from_char)
+            .chain(testing::BOUNDARY_U22S.into_iter().map(PackedChar::from_u22))
+            .collect();
+        let buffer: CompactPackedBuffer = packed.iter().copied().collect();
+        assert_eq!(buffer.len(), packed.len());
+        assert!(buffer.iter().eq(packed.iter().copied()));
+        assert_eq!(buffer.get(packed.len()), None);
+        assert_eq!(buffer.get(usize::MAX / 3), None);
+        assert_eq!(buffer.get(usize::MAX), None);
+    }
+}
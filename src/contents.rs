@@ -0,0 +1,331 @@
+use crate::{PackedChar, U22};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// The contents of a [`PackedChar`].
+///
+/// Returned from [`PackedChar::contents`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Contents {
+    Char(char),
+    U22(U22),
+}
+
+/// Formats a `char` as `Char('a')` and a [`U22`] using its own `Debug`, as `U22(0x2a)`, rather
+/// than nesting it as `U22(U22(0x2a))`.
+///
+/// ```
+/// # use packed_char::{Contents, U22};
+/// let u22 = U22::from(42u8);
+/// assert_eq!(format!("{:?}", Contents::Char('a')), "Char('a')");
+/// assert_eq!(format!("{:?}", Contents::U22(u22)), "U22(0x2a)");
+/// assert_eq!(format!("{:?}", Contents::U22(u22)), format!("{u22:?}"));
+/// ```
+impl Debug for Contents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(c) => f.debug_tuple("Char").field(c).finish(),
+            Self::U22(u22) => Debug::fmt(u22, f),
+        }
+    }
+}
+
+/// Writes a discriminant byte, `0` for a `char` and `1` for a [`U22`], followed by the code point
+/// or integer value as a `u32`. A `char` and a [`U22`] with the same value therefore hash
+/// differently. The sequence written is part of the stable API and does not depend on the
+/// [`PackedChar`] encoding.
+impl Hash for Contents {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Char(c) => {
+                state.write_u8(0);
+                state.write_u32(*c as u32);
+            }
+            Self::U22(u22) => {
+                state.write_u8(1);
+                state.write_u32(u22.as_u32());
+            }
+        }
+    }
+}
+
+impl Default for Contents {
+    /// Returns `Contents::Char('\0')`, matching the contents of [`PackedChar::default`].
+    fn default() -> Self {
+        Self::Char('\0')
+    }
+}
+
+impl Contents {
+    /// Creates `Contents::Char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::Contents;
+    /// const A: Contents = Contents::char('a');
+    /// assert_eq!(A, Contents::Char('a'));
+    /// ```
+    pub const fn char(c: char) -> Self {
+        Self::Char(c)
+    }
+
+    /// Creates `Contents::U22`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{Contents, U22};
+    /// const ONE: Contents = Contents::u22(U22::from_bool(true));
+    /// assert_eq!(ONE, Contents::U22(U22::from(1u8)));
+    /// ```
+    pub const fn u22(u22: U22) -> Self {
+        Self::U22(u22)
+    }
+
+    /// Converts into a `Result` with the `char` as `Ok` and the [`U22`] as `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{Contents, U22};
+    /// assert_eq!(Contents::Char('a').into_result(), Ok('a'));
+    /// assert_eq!(Contents::U22(U22::from(42u8)).into_result(), Err(U22::from(42u8)));
+    /// ```
+    pub const fn into_result(self) -> Result<char, U22> {
+        match self {
+            Self::Char(c) => Ok(c),
+            Self::U22(u22) => Err(u22),
+        }
+    }
+}
+
+/// Converts `Ok` into a `char` and `Err` into a [`U22`]. Reverses [`Contents::into_result`].
+impl From<Result<char, U22>> for Contents {
+    fn from(result: Result<char, U22>) -> Self {
+        match result {
+            Ok(c) => Self::Char(c),
+            Err(u22) => Self::U22(u22),
+        }
+    }
+}
+
+/// A `Contents::Char` compares against the `char`. A `Contents::U22` is ordered after every
+/// `char`, following the variant order.
+///
+/// ```
+/// # use packed_char::{Contents, U22};
+/// assert!(Contents::Char('a') < 'b');
+/// assert!(Contents::U22(U22::from(0u8)) > char::MAX);
+/// ```
+impl PartialEq<char> for Contents {
+    fn eq(&self, other: &char) -> bool {
+        *self == Contents::Char(*other)
+    }
+}
+
+impl PartialOrd<char> for Contents {
+    fn partial_cmp(&self, other: &char) -> Option<Ordering> {
+        Some(self.cmp(&Contents::Char(*other)))
+    }
+}
+
+/// A `Contents::U22` compares against the [`U22`]. A `Contents::Char` is ordered before every
+/// [`U22`], following the variant order.
+///
+/// ```
+/// # use packed_char::{Contents, U22};
+/// assert!(Contents::U22(U22::from(1u8)) < U22::from(2u8));
+/// assert!(Contents::Char(char::MAX) < U22::from(0u8));
+/// ```
+impl PartialEq<U22> for Contents {
+    fn eq(&self, other: &U22) -> bool {
+        *self == Contents::U22(*other)
+    }
+}
+
+impl PartialOrd<U22> for Contents {
+    fn partial_cmp(&self, other: &U22) -> Option<Ordering> {
+        Some(self.cmp(&Contents::U22(*other)))
+    }
+}
+
+/// Compares the contents of the [`PackedChar`], consistent with its [`Ord`] implementation.
+///
+/// ```
+/// # use packed_char::{Contents, PackedChar};
+/// assert_eq!(PackedChar::from('a'), Contents::Char('a'));
+/// assert!(PackedChar::from('a') < Contents::Char('b'));
+/// ```
+impl PartialEq<Contents> for PackedChar {
+    fn eq(&self, other: &Contents) -> bool {
+        self.contents() == *other
+    }
+}
+
+impl PartialEq<PackedChar> for Contents {
+    fn eq(&self, other: &PackedChar) -> bool {
+        *self == other.contents()
+    }
+}
+
+impl PartialOrd<Contents> for PackedChar {
+    fn partial_cmp(&self, other: &Contents) -> Option<Ordering> {
+        Some(self.contents().cmp(other))
+    }
+}
+
+impl PartialOrd<PackedChar> for Contents {
+    fn partial_cmp(&self, other: &PackedChar) -> Option<Ordering> {
+        Some(self.cmp(&other.contents()))
+    }
+}
+
+/// Conversion into [`Contents`].
+///
+/// Implemented for each type that can be stored in a [`PackedChar`], allowing functions to
+/// accept any of them uniformly.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{Contents, IntoContents, PackedChar, U22};
+/// fn is_char(value: impl IntoContents) -> bool {
+///     matches!(value.into_contents(), Contents::Char(_))
+/// }
+///
+/// assert!(is_char('a'));
+/// assert!(is_char(PackedChar::from('a')));
+/// assert!(!is_char(U22::from_u32(42).unwrap()));
+/// ```
+pub trait IntoContents: Copy {
+    /// Converts this value into [`Contents`].
+    fn into_contents(self) -> Contents;
+}
+
+impl IntoContents for Contents {
+    fn into_contents(self) -> Contents {
+        self
+    }
+}
+
+impl IntoContents for PackedChar {
+    fn into_contents(self) -> Contents {
+        self.contents()
+    }
+}
+
+impl IntoContents for char {
+    fn into_contents(self) -> Contents {
+        Contents::Char(self)
+    }
+}
+
+impl IntoContents for U22 {
+    fn into_contents(self) -> Contents {
+        Contents::U22(self)
+    }
+}
+
+/// ```
+/// # use packed_char::{Contents, U22};
+/// assert_eq!(Contents::from('a'), Contents::Char('a'));
+/// ```
+impl From<char> for Contents {
+    fn from(c: char) -> Self {
+        Self::Char(c)
+    }
+}
+
+/// ```
+/// # use packed_char::{Contents, U22};
+/// let u22 = U22::from(42u8);
+/// assert_eq!(Contents::from(u22), Contents::U22(u22));
+/// ```
+impl From<U22> for Contents {
+    fn from(u22: U22) -> Self {
+        Self::U22(u22)
+    }
+}
+
+impl From<Contents> for PackedChar {
+    #[inline]
+    fn from(contents: Contents) -> Self {
+        match contents {
+            Contents::Char(c) => Self::from_char(c),
+            Contents::U22(u22) => Self::from_u22(u22),
+        }
+    }
+}
+
+impl From<PackedChar> for Contents {
+    #[inline]
+    fn from(packed: PackedChar) -> Self {
+        packed.contents()
+    }
+}
+
+impl TryFrom<Contents> for char {
+    type Error = WrongVariantError;
+
+    fn try_from(contents: Contents) -> Result<Self, Self::Error> {
+        match contents {
+            Contents::Char(c) => Ok(c),
+            Contents::U22(_) => Err(WrongVariantError(contents)),
+        }
+    }
+}
+
+impl TryFrom<Contents> for U22 {
+    type Error = WrongVariantError;
+
+    fn try_from(contents: Contents) -> Result<Self, Self::Error> {
+        match contents {
+            Contents::Char(_) => Err(WrongVariantError(contents)),
+            Contents::U22(u22) => Ok(u22),
+        }
+    }
+}
+
+impl TryFrom<PackedChar> for char {
+    type Error = WrongVariantError;
+
+    fn try_from(packed: PackedChar) -> Result<Self, Self::Error> {
+        packed.contents().try_into()
+    }
+}
+
+impl TryFrom<PackedChar> for U22 {
+    type Error = WrongVariantError;
+
+    fn try_from(packed: PackedChar) -> Result<Self, Self::Error> {
+        packed.contents().try_into()
+    }
+}
+
+/// Error type for extracting a `char` or a [`U22`] from a value holding the other variant.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{Contents, PackedChar, U22, WrongVariantError};
+/// assert_eq!(char::try_from(PackedChar::from('a')), Ok('a'));
+/// assert_eq!(U22::try_from(Contents::Char('a')), Err(WrongVariantError(Contents::Char('a'))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WrongVariantError(
+    /// The contents that held the other variant.
+    pub Contents,
+);
+
+impl Display for WrongVariantError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Contents::Char(c) => write!(f, "expected a U22 but found the char {c:?}"),
+            Contents::U22(u22) => write!(f, "expected a char but found the U22 {u22}"),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! A C ABI for packing and unpacking.
+//!
+//! Requires the `ffi` feature. A [`PackedChar`] crosses the boundary as its raw 32-bit
+//! representation from [`PackedChar::to_bits`]. Each function returns a status code and writes its
+//! results through out-parameters, which are left untouched unless the status is
+//! [`PACKED_CHAR_OK`]. The matching C declarations are:
+//!
+//! ```c
+//! #include <stdint.h>
+//!
+//! #define PACKED_CHAR_OK 0
+//! #define PACKED_CHAR_NULL_POINTER 1
+//! #define PACKED_CHAR_OUT_OF_RANGE 2
+//! #define PACKED_CHAR_INVALID_BITS 3
+//!
+//! #define PACKED_CHAR_TAG_CHAR 0
+//! #define PACKED_CHAR_TAG_U22 1
+//!
+//! int32_t packed_char_from_char(uint32_t c, uint32_t *out);
+//! int32_t packed_char_u22_from_u32(uint32_t n, uint32_t *out);
+//! int32_t packed_char_contents(uint32_t packed, uint8_t *tag, uint32_t *value);
+//! ```
+
+use crate::{Contents, PackedChar, U22};
+
+/// The call succeeded and its out-parameters were written.
+pub const PACKED_CHAR_OK: i32 = 0;
+/// An out-parameter was null.
+pub const PACKED_CHAR_NULL_POINTER: i32 = 1;
+/// The input was not a valid `char` or [`U22`].
+pub const PACKED_CHAR_OUT_OF_RANGE: i32 = 2;
+/// The input was not a valid packed representation. See [`PackedChar::is_valid_bits`].
+pub const PACKED_CHAR_INVALID_BITS: i32 = 3;
+
+/// Tags a `char` written by [`packed_char_contents`].
+pub const PACKED_CHAR_TAG_CHAR: u8 = 0;
+/// Tags a [`U22`] written by [`packed_char_contents`].
+pub const PACKED_CHAR_TAG_U22: u8 = 1;
+
+/// Packs the Unicode scalar value `c`, writing the packed representation to `out`.
+///
+/// Returns [`PACKED_CHAR_OUT_OF_RANGE`] if `c` is not a valid `char`.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{ffi::*, PackedChar};
+/// let mut packed = 0;
+/// assert_eq!(unsafe { packed_char_from_char('a' as u32, &mut packed) }, PACKED_CHAR_OK);
+/// assert_eq!(packed, PackedChar::from('a').to_bits());
+/// assert_eq!(unsafe { packed_char_from_char(0xD800, &mut packed) }, PACKED_CHAR_OUT_OF_RANGE);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn packed_char_from_char(c: u32, out: *mut u32) -> i32 {
+    if out.is_null() {
+        return PACKED_CHAR_NULL_POINTER;
+    }
+    match char::from_u32(c) {
+        Some(c) => {
+            unsafe { out.write(PackedChar::from_char(c).to_bits()) };
+            PACKED_CHAR_OK
+        }
+        None => PACKED_CHAR_OUT_OF_RANGE,
+    }
+}
+
+/// Packs the 22-bit integer `n`, writing the packed representation to `out`.
+///
+/// Returns [`PACKED_CHAR_OUT_OF_RANGE`] if `n` exceeds [`U22::MAX`].
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{ffi::*, PackedChar, U22};
+/// let mut packed = 0;
+/// assert_eq!(unsafe { packed_char_u22_from_u32(42, &mut packed) }, PACKED_CHAR_OK);
+/// assert_eq!(packed, PackedChar::try_from(42).unwrap().to_bits());
+/// assert_eq!(
+///     unsafe { packed_char_u22_from_u32(U22::MAX + 1, &mut packed) },
+///     PACKED_CHAR_OUT_OF_RANGE
+/// );
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn packed_char_u22_from_u32(n: u32, out: *mut u32) -> i32 {
+    if out.is_null() {
+        return PACKED_CHAR_NULL_POINTER;
+    }
+    match U22::from_u32(n) {
+        Ok(u22) => {
+            unsafe { out.write(PackedChar::from_u22(u22).to_bits()) };
+            PACKED_CHAR_OK
+        }
+        Err(_) => PACKED_CHAR_OUT_OF_RANGE,
+    }
+}
+
+/// Unpacks the packed representation `packed`, writing [`PACKED_CHAR_TAG_CHAR`] or
+/// [`PACKED_CHAR_TAG_U22`] to `tag` and the `char` or integer to `value`.
+///
+/// Returns [`PACKED_CHAR_INVALID_BITS`] if `packed` is not a valid packed representation.
+///
+/// # Safety
+///
+/// `tag` and `value` must each be null or valid for writes.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{ffi::*, PackedChar};
+/// let (mut tag, mut value) = (0, 0);
+/// let packed = PackedChar::try_from(42).unwrap().to_bits();
+/// assert_eq!(unsafe { packed_char_contents(packed, &mut tag, &mut value) }, PACKED_CHAR_OK);
+/// assert_eq!((tag, value), (PACKED_CHAR_TAG_U22, 42));
+/// assert_eq!(
+///     unsafe { packed_char_contents(u32::MAX, &mut tag, &mut value) },
+///     PACKED_CHAR_INVALID_BITS
+/// );
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn packed_char_contents(packed: u32, tag: *mut u8, value: *mut u32) -> i32 {
+    if tag.is_null() || value.is_null() {
+        return PACKED_CHAR_NULL_POINTER;
+    }
+    let Ok(packed) = PackedChar::try_from_bits(packed) else {
+        return PACKED_CHAR_INVALID_BITS;
+    };
+    let (t, v) = match packed.contents() {
+        Contents::Char(c) => (PACKED_CHAR_TAG_CHAR, c as u32),
+        Contents::U22(u22) => (PACKED_CHAR_TAG_U22, u22.as_u32()),
+    };
+    unsafe {
+        tag.write(t);
+        value.write(v);
+    }
+    PACKED_CHAR_OK
+}
@@ -13,13 +13,138 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "array")]
+mod array;
+mod builder;
+mod char_class;
+pub mod checked;
+#[cfg(feature = "compact")]
+mod compact;
+mod contents;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod non_zero;
+mod option;
+#[cfg(feature = "alloc")]
+mod owned;
+pub mod prelude;
+mod saturating;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod slice;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod u22;
-pub use u22::{U22FromU32Error, U22};
+mod wrapping;
+#[cfg(feature = "array")]
+pub use array::PackedCharArray;
+pub use builder::{U22Builder, U22BuilderError};
+pub use char_class::CharClass;
+#[cfg(feature = "compact")]
+pub use compact::CompactPackedBuffer;
+pub use contents::{Contents, IntoContents, WrongVariantError};
+pub use non_zero::NonZeroU22;
+pub use option::OptionPackedChar;
+#[cfg(feature = "alloc")]
+pub use owned::contents_to_string;
+pub use saturating::SaturatingU22;
+pub use slice::{SentinelPolicy, SliceExt};
+pub use u22::{TryFromU22Error, U22Category, U22FromU32Error, U22};
+pub use wrapping::WrappingU22;
 
-use core::fmt::{self, Debug, Formatter};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+};
 
 /// Stores either a `char` or a [`U22`] in 32 bits of space.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+///
+/// # Equality
+///
+/// Values are equal only if they hold the same variant with the same payload. A `char` and a
+/// [`U22`] never compare equal, even when the code point and the integer are numerically the
+/// same, so the variant is significant when a `PackedChar` is used as a map key.
+///
+/// ```
+/// # use packed_char::{PackedChar, U22};
+/// let c = PackedChar::from_char('*');
+/// let u22 = PackedChar::from_u22(U22::from_u32('*' as u32).unwrap());
+/// assert_ne!(c, u22);
+/// ```
+///
+/// Because the variant matters, a map keyed by `PackedChar` cannot be queried with a bare `char`
+/// or `u32` through [`Borrow`](core::borrow::Borrow): no single borrowed form hashes the same as
+/// both variants. Construct the key instead, which is free for a `char`.
+///
+/// ```
+/// # use packed_char::PackedChar;
+/// # use std::collections::HashMap;
+/// let mut map = HashMap::new();
+/// map.insert(PackedChar::from('a'), 1);
+/// assert_eq!(map.get(&PackedChar::from('a')), Some(&1));
+/// ```
+///
+/// # Ordering
+///
+/// Every `char` orders before every [`U22`]. Within each variant, values are ordered by code
+/// point or by integer value. This matches the ordering of [`Contents`] and of
+/// [`PackedChar::sort_key`].
+///
+/// ```
+/// # use packed_char::PackedChar;
+/// let u22 = PackedChar::try_from(0).unwrap();
+/// assert!(PackedChar::from('a') < PackedChar::from('b'));
+/// assert!(PackedChar::from(char::MAX) < u22);
+/// ```
+///
+/// Ordered collections such as `BTreeMap` therefore iterate over all `char`s before any
+/// [`U22`]s:
+///
+/// ```
+/// # use packed_char::PackedChar;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<_, _> = [
+///     (PackedChar::try_from(1).unwrap(), "one"),
+///     (PackedChar::from('b'), "b"),
+///     (PackedChar::try_from(0).unwrap(), "zero"),
+///     (PackedChar::from('a'), "a"),
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert!(map.values().eq(&["a", "b", "zero", "one"]));
+/// ```
+///
+/// To interleave the variants by value instead, use [`PackedChar::cmp_by_value`].
+///
+/// Versions 0.1.2 and earlier ordered by the raw 32-bit representation instead. Data sorted by
+/// those versions must be re-sorted, or sorted by [`PackedChar::to_bits`] to keep the old order.
+///
+/// # Layout
+///
+/// `PackedChar` is `#[repr(transparent)]` over a `u32`, so it has the same size, alignment, and
+/// ABI as `u32`. A slice of `PackedChar` may be reinterpreted as a slice of `u32`. The reverse is
+/// only sound if every element passes [`PackedChar::try_from_bits`].
+///
+/// # Performance
+///
+/// The constructors, [`PackedChar::contents`], and the conversions between them are marked
+/// `#[inline]` so they can be inlined across crates. Packing with [`PackedChar::from_u22`] is a
+/// branchless handful of shifts and masks. Unpacking with [`PackedChar::contents`] needs a single
+/// comparison to tell the variants apart. When the variant is known, as in
+/// `PackedChar::from_u22(u22).contents()`, optimized builds fold the round trip down to a mask.
+///
+/// # Stability
+///
+/// The raw bit layout returned by [`PackedChar::to_bits`] and accepted by
+/// [`PackedChar::try_from_bits`] is part of the public API and suitable for persisting. It is
+/// described in [`PackedChar::is_u22_pattern`]. The layout will only change in a
+/// semver-incompatible release, together with an increment of [`PackedChar::FORMAT_VERSION`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
 pub struct PackedChar(u32);
 
 impl PackedChar {
@@ -31,6 +156,11 @@ impl PackedChar {
     const TRAILING: u32 = Self::SURROGATE_LOW.trailing_zeros(); // 11
     const TRAILING_MASK: u32 = !(u32::MAX << Self::TRAILING);
     const MAX_U22_LEADING: u32 = U22::MAX.leading_zeros();
+    const SIGNATURE_MASK: u32 = !(Self::LEADING_MASK | Self::TRAILING_MASK);
+
+    /// The version of the raw bit layout. Store this alongside persisted bits to detect
+    /// incompatible layouts.
+    pub const FORMAT_VERSION: u32 = 1;
 
     /// Creates a new value from the given `char`.
     ///
@@ -41,6 +171,7 @@ impl PackedChar {
     /// let pack = PackedChar::from_char('a');
     /// assert_eq!(pack.contents(), Contents::Char('a'));
     /// ```
+    #[inline]
     pub const fn from_char(c: char) -> Self {
         Self(c as u32)
     }
@@ -55,6 +186,7 @@ impl PackedChar {
     /// let pack = PackedChar::from_u22(u22);
     /// assert_eq!(pack.contents(), Contents::U22(u22));
     /// ```
+    #[inline]
     pub const fn from_u22(u22: U22) -> Self {
         let n = u22.as_u32();
         let leading = (n << Self::MAX_U22_LEADING) & Self::LEADING_MASK;
@@ -77,33 +209,481 @@ impl PackedChar {
     /// # Ok(())
     /// # }
     /// ```
+    #[inline]
     pub const fn contents(self) -> Contents {
         match char::from_u32(self.0) {
             Some(c) => Contents::Char(c),
-            None => {
-                let trailing = self.0 & Self::TRAILING_MASK;
-                let leading = self.0 & Self::LEADING_MASK;
-                let u22 = trailing | (leading >> Self::MAX_U22_LEADING);
-                // SAFETY: Valid by construction since we reversed the storage procedure.
-                Contents::U22(unsafe { U22::from_u32_unchecked(u22) })
-            }
+            None => Contents::U22(self.decode_u22()),
+        }
+    }
+
+    /// Gets a key whose ordering matches the [`Ord`] implementation. The variant is stored in
+    /// the high 32 bits and the code point or integer value in the low 32 bits.
+    ///
+    /// Methods like `sort_unstable_by_key` recompute the key for both operands on every
+    /// comparison. To decode each element only once, use `sort_by_cached_key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let mut packed = [
+    ///     PackedChar::try_from(1).unwrap(),
+    ///     PackedChar::from('b'),
+    ///     PackedChar::from('a'),
+    /// ];
+    /// packed.sort_by_cached_key(|p| p.sort_key());
+    /// assert_eq!(packed, [PackedChar::from('a'), PackedChar::from('b'), PackedChar::try_from(1).unwrap()]);
+    /// ```
+    pub const fn sort_key(self) -> u64 {
+        match self.contents() {
+            Contents::Char(c) => c as u64,
+            Contents::U22(u22) => 1 << u32::BITS | u22.as_u32() as u64,
+        }
+    }
+
+    /// Gets the code point of a `char` or the value of a [`U22`], discarding the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_u32_value(), 0x61);
+    /// assert_eq!(PackedChar::try_from(0x61).unwrap().to_u32_value(), 0x61);
+    /// ```
+    pub const fn to_u32_value(self) -> u32 {
+        match self.contents() {
+            Contents::Char(c) => c as u32,
+            Contents::U22(u22) => u22.as_u32(),
+        }
+    }
+
+    /// Compares by [`PackedChar::to_u32_value`] alone, so `char`s and [`U22`]s interleave.
+    /// A `char` and a [`U22`] with the same value compare as equal.
+    ///
+    /// Unlike the [`Ord`] implementation, this does not order every `char` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// # use core::cmp::Ordering;
+    /// let mut packed = [
+    ///     PackedChar::from('b'),
+    ///     PackedChar::try_from(0x61).unwrap(),
+    ///     PackedChar::from('\0'),
+    /// ];
+    /// packed.sort_by(|a, b| a.cmp_by_value(*b));
+    /// assert_eq!(packed.map(PackedChar::to_u32_value), [0, 0x61, 0x62]);
+    /// assert_eq!(
+    ///     PackedChar::from('a').cmp_by_value(PackedChar::try_from(0x61).unwrap()),
+    ///     Ordering::Equal
+    /// );
+    /// ```
+    pub const fn cmp_by_value(self, other: Self) -> Ordering {
+        let (lhs, rhs) = (self.to_u32_value(), other.to_u32_value());
+        if lhs < rhs {
+            Ordering::Less
+        } else if lhs > rhs {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Applies the closure matching the stored variant and returns the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let width = |pack: PackedChar| pack.map(|c| c.len_utf8(), |_| 0);
+    /// assert_eq!(width(PackedChar::from('🫠')), 4);
+    /// assert_eq!(width(PackedChar::try_from(42).unwrap()), 0);
+    /// ```
+    #[inline]
+    pub fn map<T>(self, on_char: impl FnOnce(char) -> T, on_u22: impl FnOnce(U22) -> T) -> T {
+        match char::from_u32(self.0) {
+            Some(c) => on_char(c),
+            None => on_u22(self.decode_u22()),
+        }
+    }
+
+    /// Whether the value holds a `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert!(PackedChar::from('a').is_char());
+    /// assert!(!PackedChar::try_from(42).unwrap().is_char());
+    /// ```
+    pub const fn is_char(self) -> bool {
+        !self.is_u22()
+    }
+
+    /// Whether the value holds a [`U22`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert!(PackedChar::try_from(42).unwrap().is_u22());
+    /// assert!(!PackedChar::from('a').is_u22());
+    /// ```
+    pub const fn is_u22(self) -> bool {
+        Self::is_u22_pattern(self.0)
+    }
+
+    /// Gets the stored `char` without checking the variant.
+    ///
+    /// # Safety
+    ///
+    /// The value must hold a `char`, as reported by [`PackedChar::is_char`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let packed = PackedChar::from('a');
+    /// if packed.is_char() {
+    ///     // SAFETY: Just checked the variant.
+    ///     assert_eq!(unsafe { packed.char_unchecked() }, 'a');
+    /// }
+    /// ```
+    pub const unsafe fn char_unchecked(self) -> char {
+        // SAFETY: The caller guarantees that the value holds a char.
+        unsafe { char::from_u32_unchecked(self.0) }
+    }
+
+    /// Gets the stored [`U22`] without checking the variant.
+    ///
+    /// # Safety
+    ///
+    /// The value must hold a [`U22`], as reported by [`PackedChar::is_u22`]. Calling this on a
+    /// `char` is undefined behavior, because the optimizer is told that the variant check holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let packed = PackedChar::try_from(42).unwrap();
+    /// if packed.is_u22() {
+    ///     // SAFETY: Just checked the variant.
+    ///     assert_eq!(unsafe { packed.u22_unchecked() }.as_u32(), 42);
+    /// }
+    /// ```
+    pub const unsafe fn u22_unchecked(self) -> U22 {
+        // SAFETY: The caller guarantees that the value holds a U22.
+        unsafe { core::hint::assert_unchecked(self.is_u22()) };
+        self.decode_u22()
+    }
+
+    /// Whether the value holds an ASCII `char`. Always `false` for a [`U22`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert!(PackedChar::from('a').is_ascii());
+    /// assert!(!PackedChar::from('🫠').is_ascii());
+    /// assert!(!PackedChar::try_from(42).unwrap().is_ascii());
+    /// ```
+    pub const fn is_ascii(self) -> bool {
+        match self.contents() {
+            Contents::Char(c) => c.is_ascii(),
+            Contents::U22(_) => false,
+        }
+    }
+
+    /// Whether the value holds an ASCII decimal digit. Always `false` for a [`U22`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert!(PackedChar::from('7').is_ascii_digit());
+    /// assert!(!PackedChar::from('a').is_ascii_digit());
+    /// assert!(!PackedChar::try_from(7).unwrap().is_ascii_digit());
+    /// ```
+    pub const fn is_ascii_digit(self) -> bool {
+        match self.contents() {
+            Contents::Char(c) => c.is_ascii_digit(),
+            Contents::U22(_) => false,
+        }
+    }
+
+    /// Makes a copy with an ASCII `char` mapped to uppercase. Other `char`s and [`U22`]s are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_ascii_uppercase(), PackedChar::from('A'));
+    /// let u22 = PackedChar::try_from(0x61).unwrap();
+    /// assert_eq!(u22.to_ascii_uppercase(), u22);
+    /// ```
+    pub const fn to_ascii_uppercase(self) -> Self {
+        match self.contents() {
+            Contents::Char(c) => Self::from_char(c.to_ascii_uppercase()),
+            Contents::U22(_) => self,
+        }
+    }
+
+    /// Makes a copy with an ASCII `char` mapped to lowercase. Other `char`s and [`U22`]s are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('A').to_ascii_lowercase(), PackedChar::from('a'));
+    /// let u22 = PackedChar::try_from(0x41).unwrap();
+    /// assert_eq!(u22.to_ascii_lowercase(), u22);
+    /// ```
+    pub const fn to_ascii_lowercase(self) -> Self {
+        match self.contents() {
+            Contents::Char(c) => Self::from_char(c.to_ascii_lowercase()),
+            Contents::U22(_) => self,
+        }
+    }
+
+    /// Converts an ASCII `char` to uppercase in place. Leaves other `char`s and [`U22`]s
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let mut packed = [PackedChar::from('a'), PackedChar::try_from(0x61).unwrap()];
+    /// packed.iter_mut().for_each(PackedChar::make_ascii_uppercase);
+    /// assert_eq!(packed, [PackedChar::from('A'), PackedChar::try_from(0x61).unwrap()]);
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        *self = self.to_ascii_uppercase();
+    }
+
+    /// Converts an ASCII `char` to lowercase in place. Leaves other `char`s and [`U22`]s
+    /// untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let mut packed = [PackedChar::from('A'), PackedChar::try_from(0x41).unwrap()];
+    /// packed.iter_mut().for_each(PackedChar::make_ascii_lowercase);
+    /// assert_eq!(packed, [PackedChar::from('a'), PackedChar::try_from(0x41).unwrap()]);
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        *self = self.to_ascii_lowercase();
+    }
+
+    /// Reverses [`PackedChar::from_u22`]. Only meaningful if the value holds a [`U22`].
+    #[inline]
+    const fn decode_u22(self) -> U22 {
+        let trailing = self.0 & Self::TRAILING_MASK;
+        let leading = self.0 & Self::LEADING_MASK;
+        let u22 = trailing | (leading >> Self::MAX_U22_LEADING);
+        // SAFETY: Valid by construction since we reversed the storage procedure.
+        unsafe { U22::from_u32_unchecked(u22) }
+    }
+
+    /// Gets the raw 32-bit representation of this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_bits(), 'a' as u32);
+    /// ```
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Creates a new value from its raw 32-bit representation if it is a pattern that
+    /// [`PackedChar::from_char`] or [`PackedChar::from_u22`] could have produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharFromBitsError};
+    /// let pack = PackedChar::try_from(42).unwrap();
+    /// assert_eq!(PackedChar::try_from_bits(pack.to_bits()), Ok(pack));
+    /// assert_eq!(PackedChar::try_from_bits(u32::MAX), Err(PackedCharFromBitsError(u32::MAX)));
+    /// ```
+    pub const fn try_from_bits(bits: u32) -> Result<Self, PackedCharFromBitsError> {
+        if Self::is_valid_bits(bits) {
+            Ok(Self(bits))
+        } else {
+            Err(PackedCharFromBitsError(bits))
         }
     }
+
+    /// Whether the raw 32-bit representation is a pattern that [`PackedChar::from_char`] or
+    /// [`PackedChar::from_u22`] could have produced.
+    ///
+    /// A pattern is accepted if it is a valid `char`, or if it has the [`U22`] signature
+    /// described in [`PackedChar::is_u22_pattern`]. Every other pattern is rejected. That is,
+    /// a pattern is rejected if it is above [`char::MAX`] or a surrogate code point, and bits 11
+    /// to 20 are not `0b00000_11011`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// const VALID: bool = PackedChar::is_valid_bits(0x61);
+    /// assert!(VALID);
+    /// assert!(PackedChar::is_valid_bits(0xD800));
+    /// assert!(!PackedChar::is_valid_bits(0x11_0000));
+    /// assert!(!PackedChar::is_valid_bits(u32::MAX));
+    /// ```
+    pub const fn is_valid_bits(bits: u32) -> bool {
+        char::from_u32(bits).is_some() || Self::is_u22_pattern(bits)
+    }
+
+    /// Whether the raw 32-bit representation holds a [`U22`].
+    ///
+    /// A [`U22`] is split into two 11-bit chunks. The high chunk is stored in bits 21 to 31 and
+    /// the low chunk in bits 0 to 10. Bits 11 to 20 hold the signature `0b00000_11011`, the
+    /// leading bits shared by all surrogate code points. Any pattern with this signature is
+    /// either a surrogate code point or exceeds [`char::MAX`], so it is never a valid `char`.
+    ///
+    /// ```text
+    /// 11111111111  00000    11011            11111111111
+    /// high chunk | unused | surrogate mask | low chunk
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert!(PackedChar::is_u22_pattern(PackedChar::try_from(42).unwrap().to_bits()));
+    /// assert!(PackedChar::is_u22_pattern(0xFFE0_DFFF));
+    /// assert!(!PackedChar::is_u22_pattern(PackedChar::from('a').to_bits()));
+    /// ```
+    pub const fn is_u22_pattern(bits: u32) -> bool {
+        bits & Self::SIGNATURE_MASK == Self::SURROGATE_MASK
+    }
+
+    /// Returns the memory representation of this value as a byte array in little-endian byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_le_bytes(), [0x61, 0, 0, 0]);
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns the memory representation of this value as a byte array in big-endian byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_be_bytes(), [0, 0, 0, 0x61]);
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Creates a new value from its representation as a byte array in little-endian byte order
+    /// if it is a valid pattern. See [`PackedChar::try_from_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharFromBitsError};
+    /// assert_eq!(PackedChar::from_le_bytes([0x61, 0, 0, 0]), Ok(PackedChar::from('a')));
+    /// assert_eq!(PackedChar::from_le_bytes([0xFF; 4]), Err(PackedCharFromBitsError(u32::MAX)));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 4]) -> Result<Self, PackedCharFromBitsError> {
+        Self::try_from_bits(u32::from_le_bytes(bytes))
+    }
+
+    /// Creates a new value from its representation as a byte array in big-endian byte order if
+    /// it is a valid pattern. See [`PackedChar::try_from_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharFromBitsError};
+    /// assert_eq!(PackedChar::from_be_bytes([0, 0, 0, 0x61]), Ok(PackedChar::from('a')));
+    /// assert_eq!(PackedChar::from_be_bytes([0xFF; 4]), Err(PackedCharFromBitsError(u32::MAX)));
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 4]) -> Result<Self, PackedCharFromBitsError> {
+        Self::try_from_bits(u32::from_be_bytes(bytes))
+    }
 }
 
+/// Formats the contents. The alternate flag (`{:#?}`) also shows the raw bits.
+///
+/// ```
+/// # use packed_char::PackedChar;
+/// assert_eq!(format!("{:?}", PackedChar::from('a')), "Char('a')");
+/// assert_eq!(
+///     format!("{:#?}", PackedChar::from('a')),
+///     "PackedChar {\n    contents: Char(\n        'a',\n    ),\n    bits: 0x00000061,\n}",
+/// );
+/// ```
 impl Debug for PackedChar {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.contents())
+        if f.alternate() {
+            f.debug_struct("PackedChar")
+                .field("contents", &self.contents())
+                .field("bits", &format_args!("{:#010x}", self.0))
+                .finish()
+        } else {
+            write!(f, "{:?}", self.contents())
+        }
+    }
+}
+
+/// Writes a `char` as itself and a [`U22`] as a decimal number. Use `to_string` for a `String`
+/// that always succeeds, or `PackedChar::to_string_opt` from the `alloc` feature to only accept
+/// `char`s.
+///
+/// ```
+/// # use packed_char::PackedChar;
+/// assert_eq!(format!("{}", PackedChar::from('a')), "a");
+/// assert_eq!(PackedChar::try_from(42).unwrap().to_string(), "42");
+/// ```
+impl Display for PackedChar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.contents() {
+            Contents::Char(c) => Display::fmt(&c, f),
+            Contents::U22(u22) => Display::fmt(&u22, f),
+        }
+    }
+}
+
+impl PartialOrd for PackedChar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PackedChar {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
     }
 }
 
 impl From<char> for PackedChar {
+    #[inline]
     fn from(c: char) -> Self {
         Self::from_char(c)
     }
 }
 
 impl From<U22> for PackedChar {
+    #[inline]
     fn from(u22: U22) -> Self {
         Self::from_u22(u22)
     }
@@ -112,19 +692,28 @@ impl From<U22> for PackedChar {
 impl TryFrom<u32> for PackedChar {
     type Error = U22FromU32Error;
 
+    #[inline]
     fn try_from(n: u32) -> Result<Self, Self::Error> {
         let u22 = U22::from_u32(n)?;
         Ok(Self::from_u22(u22))
     }
 }
 
-/// The contents of a [`PackedChar`].
-///
-/// Returned from [`PackedChar::contents`].
+/// Error type for creating a [`PackedChar`] from raw bits.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Contents {
-    Char(char),
-    U22(U22),
+pub struct PackedCharFromBitsError(
+    /// The `u32` that is not a valid [`PackedChar`] representation.
+    pub u32,
+);
+
+impl Display for PackedCharFromBitsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:#010x} is not a valid PackedChar representation",
+            self.0
+        )
+    }
 }
 
 #[cfg(test)]
@@ -172,4 +761,160 @@ mod tests {
             assert_eq!(packed, Err(U22FromU32Error(i)));
         }
     }
+
+    #[test]
+    fn roundtrips_boundaries() {
+        for c in testing::BOUNDARY_CHARS {
+            assert!(PackedChar::roundtrip(Contents::Char(c)));
+        }
+        for u22 in testing::BOUNDARY_U22S {
+            assert!(PackedChar::roundtrip(Contents::U22(u22)));
+        }
+    }
+
+    #[test]
+    fn variants_never_equal() {
+        for n in 0..=char::MAX as u32 {
+            if let [Some(c), Some(u22)] = Contents::variants_for(n) {
+                assert_ne!(PackedChar::from(c), PackedChar::from(u22));
+            }
+        }
+    }
+
+    #[test]
+    fn has_u32_layout() {
+        use core::mem::{align_of, size_of};
+        assert_eq!(size_of::<PackedChar>(), size_of::<u32>());
+        assert_eq!(align_of::<PackedChar>(), align_of::<u32>());
+        assert_eq!(size_of::<U22>(), size_of::<u32>());
+        assert_eq!(align_of::<U22>(), align_of::<u32>());
+        assert_eq!(size_of::<OptionPackedChar>(), size_of::<u32>());
+    }
+
+    #[test]
+    fn orders_like_contents() {
+        let boundaries = || {
+            testing::BOUNDARY_CHARS
+                .into_iter()
+                .map(PackedChar::from_char)
+                .chain(testing::BOUNDARY_U22S.into_iter().map(PackedChar::from_u22))
+        };
+        for a in boundaries() {
+            for b in boundaries() {
+                assert_eq!(a.cmp(&b), a.contents().cmp(&b.contents()));
+                assert_eq!(a.cmp(&b), a.sort_key().cmp(&b.sort_key()));
+                assert_eq!(a.partial_cmp(&b.contents()), Some(a.cmp(&b)));
+                assert_eq!(a == b.contents(), a == b);
+            }
+        }
+    }
+
+    #[test]
+    fn default_contents_match() {
+        assert_eq!(PackedChar::default().contents(), Contents::default());
+    }
+
+    #[test]
+    fn distinguishes_none() {
+        assert_eq!(OptionPackedChar::NONE.get(), None);
+        assert_eq!(
+            PackedChar::try_from_bits(u32::MAX),
+            Err(PackedCharFromBitsError(u32::MAX))
+        );
+        for c in ['\0', char::MAX] {
+            assert!(OptionPackedChar::some(PackedChar::from_char(c)).is_some());
+        }
+        for i in [0, U22::MAX] {
+            let packed = PackedChar::try_from(i).unwrap();
+            assert!(OptionPackedChar::some(packed).is_some());
+        }
+    }
+
+    #[test]
+    fn validates_bits() {
+        for c in ['\0', '\u{D7FF}', '\u{E000}', char::MAX, 'a'] {
+            let packed = PackedChar::from_char(c);
+            assert_eq!(PackedChar::try_from_bits(packed.to_bits()), Ok(packed));
+        }
+        for i in [U22::MAX, 0, 42, 0b1010101010101010101010] {
+            let packed = PackedChar::try_from(i).unwrap();
+            assert_eq!(PackedChar::try_from_bits(packed.to_bits()), Ok(packed));
+        }
+        let invalid = [0x0011_0000, 0x0020_1000, 0xFFFF_0000, u32::MAX];
+        for bits in invalid {
+            assert_eq!(
+                PackedChar::try_from_bits(bits),
+                Err(PackedCharFromBitsError(bits))
+            );
+        }
+    }
+
+    #[test]
+    fn debug_tags_variants() {
+        extern crate std;
+        use std::format;
+
+        let c = PackedChar::from_char('\u{7}');
+        assert_eq!(format!("{c:?}"), "Char('\\u{7}')");
+        let u = PackedChar::from_u22(U22::from_u32(42).unwrap());
+        assert_eq!(format!("{u:?}"), "U22(0x2a)");
+        assert!(format!("{u:#?}").contains("0x2a"));
+    }
+
+    #[test]
+    fn predicates_match_contents() {
+        use crate::testing::{BOUNDARY_CHARS, BOUNDARY_U22S};
+
+        for c in BOUNDARY_CHARS {
+            let packed = PackedChar::from_char(c);
+            assert!(packed.is_char() && !packed.is_u22());
+            assert_eq!(unsafe { packed.char_unchecked() }, c);
+        }
+        for u22 in BOUNDARY_U22S {
+            let packed = PackedChar::from_u22(u22);
+            assert!(packed.is_u22() && !packed.is_char());
+            assert_eq!(unsafe { packed.u22_unchecked() }, u22);
+        }
+    }
+
+    #[test]
+    fn btree_map_iterates_in_order() {
+        extern crate std;
+        use crate::testing::{BOUNDARY_CHARS, BOUNDARY_U22S};
+        use std::{collections::BTreeMap, vec::Vec};
+
+        let chars = BOUNDARY_CHARS.map(Contents::Char);
+        let u22s = BOUNDARY_U22S.map(Contents::U22);
+        let map: BTreeMap<_, _> = u22s
+            .into_iter()
+            .chain(chars)
+            .map(|contents| (PackedChar::from(contents), contents))
+            .collect();
+        let mut expected: Vec<_> = chars.into_iter().chain(u22s).collect();
+        expected.sort();
+        assert!(map.values().eq(expected.iter()));
+        let split = map.values().position(|c| matches!(c, Contents::U22(_)));
+        assert_eq!(split, Some(BOUNDARY_CHARS.len()));
+    }
+
+    #[test]
+    fn contents_hash_is_tagged() {
+        extern crate std;
+        use core::hash::{BuildHasher, Hasher};
+        use std::collections::hash_map::RandomState;
+
+        let state = RandomState::new();
+        for n in [0, 0x61, 0xD7FF, char::MAX as u32] {
+            let [Some(c), Some(u22)] = Contents::variants_for(n) else {
+                unreachable!()
+            };
+            assert_ne!(state.hash_one(c), state.hash_one(u22));
+            for (contents, tag) in [(c, 0), (u22, 1)] {
+                let mut expected = state.build_hasher();
+                expected.write_u8(tag);
+                expected.write_u32(n);
+                assert_eq!(state.hash_one(contents), expected.finish());
+            }
+        }
+    }
 }
@@ -0,0 +1,100 @@
+use crate::{U22FromU32Error, U22};
+use core::{
+    fmt::{self, Display, Formatter},
+    num::NonZeroU32,
+};
+
+/// A 22-bit unsigned integer that is known not to equal zero.
+///
+/// This enables some memory layout optimization. For example, `Option<NonZeroU22>` is the same
+/// size as `u32`.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{NonZeroU22, U22};
+/// let n = NonZeroU22::new(U22::from(42u8)).unwrap();
+/// assert_eq!(n.get().as_u32(), 42);
+/// assert_eq!(NonZeroU22::new(U22::from(0u8)), None);
+/// assert_eq!(size_of::<Option<NonZeroU22>>(), size_of::<u32>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroU22(NonZeroU32);
+
+impl NonZeroU22 {
+    /// Creates a non-zero integer if the given value is not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{NonZeroU22, U22};
+    /// assert!(NonZeroU22::new(U22::from(42u8)).is_some());
+    /// assert_eq!(NonZeroU22::new(U22::from(0u8)), None);
+    /// ```
+    pub const fn new(u22: U22) -> Option<Self> {
+        match NonZeroU32::new(u22.as_u32()) {
+            Some(n) => Some(Self(n)),
+            None => None,
+        }
+    }
+
+    /// Creates a non-zero integer without checking whether the value is zero.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{NonZeroU22, U22};
+    /// let n = unsafe { NonZeroU22::new_unchecked(U22::from(42u8)) };
+    /// assert_eq!(n.get().as_u32(), 42);
+    /// ```
+    pub const unsafe fn new_unchecked(u22: U22) -> Self {
+        // SAFETY: Upheld by the caller.
+        Self(unsafe { NonZeroU32::new_unchecked(u22.as_u32()) })
+    }
+
+    /// Gets the value as a [`U22`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{NonZeroU22, U22};
+    /// let u22 = U22::from(42u8);
+    /// assert_eq!(NonZeroU22::new(u22).unwrap().get(), u22);
+    /// ```
+    pub const fn get(self) -> U22 {
+        // SAFETY: Only constructed from a valid U22.
+        unsafe { U22::from_u32_unchecked(self.0.get()) }
+    }
+}
+
+impl Display for NonZeroU22 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<NonZeroU22> for U22 {
+    fn from(n: NonZeroU22) -> Self {
+        n.get()
+    }
+}
+
+impl From<NonZeroU22> for NonZeroU32 {
+    fn from(n: NonZeroU22) -> Self {
+        n.0
+    }
+}
+
+impl TryFrom<NonZeroU32> for NonZeroU22 {
+    type Error = U22FromU32Error;
+
+    fn try_from(n: NonZeroU32) -> Result<Self, Self::Error> {
+        U22::from_u32(n.get())?;
+        Ok(Self(n))
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Contents, PackedChar};
+use core::fmt::{self, Debug, Formatter};
+
+/// Stores an optional [`PackedChar`] in 32 bits of space.
+///
+/// Equivalent to `Option<PackedChar>`, which would otherwise take 64 bits. The empty state is
+/// stored as `u32::MAX`. This is a pattern that no `char` or [`U22`](crate::U22) encoding can
+/// produce. It exceeds [`char::MAX`], and bits 16 to 20 are set, so it does not match the
+/// [`U22`](crate::U22) signature. See [`PackedChar::is_u22_pattern`] for the bit layout.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{OptionPackedChar, PackedChar};
+/// let slot = OptionPackedChar::from(Some(PackedChar::from('a')));
+/// assert_eq!(slot.get(), Some(PackedChar::from('a')));
+/// assert!(OptionPackedChar::NONE.is_none());
+/// assert_eq!(size_of::<OptionPackedChar>(), size_of::<PackedChar>());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct OptionPackedChar(u32);
+
+impl OptionPackedChar {
+    /// The empty value.
+    pub const NONE: Self = Self(u32::MAX);
+
+    /// Creates a new occupied value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// let slot = OptionPackedChar::some(PackedChar::from('a'));
+    /// assert_eq!(slot.get(), Some(PackedChar::from('a')));
+    /// ```
+    pub const fn some(packed: PackedChar) -> Self {
+        Self(packed.0)
+    }
+
+    /// Creates a new value from the given `Option`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// assert_eq!(OptionPackedChar::new(None), OptionPackedChar::NONE);
+    /// ```
+    pub const fn new(packed: Option<PackedChar>) -> Self {
+        match packed {
+            Some(packed) => Self::some(packed),
+            None => Self::NONE,
+        }
+    }
+
+    /// Gets the stored value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// assert_eq!(OptionPackedChar::NONE.get(), None);
+    /// let slot = OptionPackedChar::some(PackedChar::from('a'));
+    /// assert_eq!(slot.get(), Some(PackedChar::from('a')));
+    /// ```
+    pub const fn get(self) -> Option<PackedChar> {
+        if self.is_none() {
+            None
+        } else {
+            Some(PackedChar(self.0))
+        }
+    }
+
+    /// Whether the value is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// assert!(OptionPackedChar::NONE.is_none());
+    /// assert!(!OptionPackedChar::some(PackedChar::from('a')).is_none());
+    /// ```
+    pub const fn is_none(self) -> bool {
+        self.0 == Self::NONE.0
+    }
+
+    /// Whether the value is occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// assert!(OptionPackedChar::some(PackedChar::from('a')).is_some());
+    /// assert!(!OptionPackedChar::NONE.is_some());
+    /// ```
+    pub const fn is_some(self) -> bool {
+        !self.is_none()
+    }
+
+    /// Gets the stored `char`, or `None` if the value is empty or holds a [`U22`](crate::U22).
+    /// Reverses the conversion from `Option<char>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{OptionPackedChar, PackedChar};
+    /// assert_eq!(OptionPackedChar::from(Some('a')).to_option_char(), Some('a'));
+    /// assert_eq!(OptionPackedChar::from(None::<char>).to_option_char(), None);
+    /// let u22 = OptionPackedChar::some(PackedChar::try_from(42).unwrap());
+    /// assert_eq!(u22.to_option_char(), None);
+    /// ```
+    pub const fn to_option_char(self) -> Option<char> {
+        match self.get() {
+            Some(packed) => match packed.contents() {
+                Contents::Char(c) => Some(c),
+                Contents::U22(_) => None,
+            },
+            None => None,
+        }
+    }
+}
+
+impl Default for OptionPackedChar {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl Debug for OptionPackedChar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.get())
+    }
+}
+
+impl From<PackedChar> for OptionPackedChar {
+    fn from(packed: PackedChar) -> Self {
+        Self::some(packed)
+    }
+}
+
+impl From<Option<PackedChar>> for OptionPackedChar {
+    fn from(packed: Option<PackedChar>) -> Self {
+        Self::new(packed)
+    }
+}
+
+/// Maps `None` to [`OptionPackedChar::NONE`], so a stream of `Option<char>` can be collected
+/// without a separate `Option` layer.
+///
+/// ```
+/// # use packed_char::{OptionPackedChar, PackedChar};
+/// assert_eq!(OptionPackedChar::from(Some('a')).get(), Some(PackedChar::from('a')));
+/// assert_eq!(OptionPackedChar::from(None::<char>), OptionPackedChar::NONE);
+/// ```
+impl From<Option<char>> for OptionPackedChar {
+    fn from(c: Option<char>) -> Self {
+        Self::new(c.map(PackedChar::from_char))
+    }
+}
+
+impl From<OptionPackedChar> for Option<PackedChar> {
+    fn from(packed: OptionPackedChar) -> Self {
+        packed.get()
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Contents, PackedChar, U22FromU32Error, U22};
+use alloc::{string::String, vec::Vec};
+
+/// Collects contents into a `String`, replacing each [`U22`] with `sentinel`.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{contents_to_string, Contents, U22};
+/// let contents = [Contents::Char('h'), Contents::U22(U22::from(42u8)), Contents::Char('i')];
+/// assert_eq!(contents_to_string(contents, '_'), "h_i");
+/// ```
+pub fn contents_to_string(iter: impl IntoIterator<Item = Contents>, sentinel: char) -> String {
+    let iter = iter.into_iter();
+    let mut out = String::with_capacity(iter.size_hint().0);
+    for contents in iter {
+        match contents {
+            Contents::Char(c) => out.push(c),
+            Contents::U22(_) => out.push(sentinel),
+        }
+    }
+    out
+}
+
+impl U22 {
+    /// Collects 32-bit integers into 22-bit integers, stopping at the first one that is out of
+    /// range. See [`U22::try_fill`] to avoid allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first element that exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// assert_eq!(U22::try_collect([1, 2]), Ok(vec![U22::from(1u8), U22::from(2u8)]));
+    /// assert_eq!(U22::try_collect([1, u32::MAX, 3]), Err(U22FromU32Error(u32::MAX)));
+    /// ```
+    pub fn try_collect(iter: impl IntoIterator<Item = u32>) -> Result<Vec<Self>, U22FromU32Error> {
+        let iter = iter.into_iter();
+        let mut out = Vec::with_capacity(iter.size_hint().0);
+        for n in iter {
+            out.push(Self::from_u32(n)?);
+        }
+        Ok(out)
+    }
+
+    /// Formats the value in the given radix, using lowercase letters for digits above 9.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from(42u8);
+    /// assert_eq!(u22.to_string_radix(2), "101010");
+    /// assert_eq!(u22.to_string_radix(36), "16");
+    /// assert_eq!(U22::from(0u8).to_string_radix(7), "0");
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().to_string_radix(16), "3fffff");
+    /// ```
+    pub fn to_string_radix(self, radix: u32) -> String {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must lie in the range `[2, 36]`"
+        );
+        let mut digits = [0u8; U22::BITS as usize];
+        let mut len = 0;
+        let mut n = self.as_u32();
+        loop {
+            digits[len] = char::from_digit(n % radix, radix).unwrap() as u8;
+            len += 1;
+            n /= radix;
+            if n == 0 {
+                break;
+            }
+        }
+        digits[..len].iter().rev().map(|&d| d as char).collect()
+    }
+}
+
+impl PackedChar {
+    /// Gets a `String` holding the `char`, or `None` for a [`U22`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// assert_eq!(PackedChar::from('a').to_string_opt().as_deref(), Some("a"));
+    /// assert_eq!(PackedChar::try_from(42).unwrap().to_string_opt(), None);
+    /// ```
+    pub fn to_string_opt(self) -> Option<String> {
+        match self.contents() {
+            Contents::Char(c) => Some(String::from(c)),
+            Contents::U22(_) => None,
+        }
+    }
+
+    /// Removes the `char`s for which `keep` returns `false`, preserving every [`U22`] and the
+    /// order of what remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let sentinel = PackedChar::try_from(42).unwrap();
+    /// let mut buf = vec![PackedChar::from('a'), sentinel, PackedChar::from(' '), PackedChar::from('b')];
+    /// PackedChar::retain_chars(&mut buf, |c| !c.is_whitespace());
+    /// assert_eq!(buf, [PackedChar::from('a'), sentinel, PackedChar::from('b')]);
+    /// ```
+    pub fn retain_chars(buf: &mut Vec<Self>, mut keep: impl FnMut(char) -> bool) {
+        buf.retain(|packed| match packed.contents() {
+            Contents::Char(c) => keep(c),
+            Contents::U22(_) => true,
+        });
+    }
+
+    /// Removes the [`U22`]s for which `keep` returns `false`, preserving every `char` and the
+    /// order of what remains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let mut buf = vec![PackedChar::from('a'), PackedChar::try_from(1).unwrap(), PackedChar::try_from(2).unwrap()];
+    /// PackedChar::retain_u22(&mut buf, |u22| u22.is_even());
+    /// assert_eq!(buf, [PackedChar::from('a'), PackedChar::try_from(2).unwrap()]);
+    /// ```
+    pub fn retain_u22(buf: &mut Vec<Self>, mut keep: impl FnMut(U22) -> bool) {
+        buf.retain(|packed| match packed.contents() {
+            Contents::Char(_) => true,
+            Contents::U22(u22) => keep(u22),
+        });
+    }
+}
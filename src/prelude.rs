@@ -0,0 +1,9 @@
+//! Re-exports of the commonly used types and traits.
+//!
+//! ```
+//! use packed_char::prelude::*;
+//!
+//! assert_eq!('a'.into_contents(), PackedChar::from('a').contents());
+//! ```
+
+pub use crate::{Contents, IntoContents, OptionPackedChar, PackedChar, SliceExt, U22};
@@ -0,0 +1,67 @@
+use crate::U22;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Deref, Mul, Sub},
+};
+
+/// A [`U22`] whose arithmetic operators saturate at zero and [`U22::MAX`].
+///
+/// The counterpart to [`core::num::Saturating`]. See also [`WrappingU22`](crate::WrappingU22).
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{SaturatingU22, U22};
+/// let max = SaturatingU22(U22::from_u32(U22::MAX).unwrap());
+/// let one = SaturatingU22(U22::from(1u8));
+/// assert_eq!((max + one).as_u32(), U22::MAX);
+/// assert_eq!((SaturatingU22::default() - one).as_u32(), 0);
+/// assert_eq!((max * max).as_u32(), U22::MAX);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SaturatingU22(pub U22);
+
+impl Display for SaturatingU22 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for SaturatingU22 {
+    type Target = U22;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<U22> for SaturatingU22 {
+    fn from(u22: U22) -> Self {
+        Self(u22)
+    }
+}
+
+impl Add for SaturatingU22 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for SaturatingU22 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for SaturatingU22 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_mul(rhs.0))
+    }
+}
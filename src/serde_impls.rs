@@ -0,0 +1,173 @@
+use crate::{Contents, PackedChar, U22};
+use serde::{
+    de::{Error, Unexpected},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes as a number in human-readable formats and as three little-endian bytes otherwise.
+///
+/// Requires the `serde` feature.
+impl Serialize for U22 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_u32(self.as_u32())
+        } else {
+            self.to_le_bytes().serialize(serializer)
+        }
+    }
+}
+
+/// Deserializes the shape written by the [`Serialize`] implementation, rejecting values that
+/// exceed [`U22::MAX`].
+///
+/// Requires the `serde` feature.
+impl<'de> Deserialize<'de> for U22 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let u22 = if deserializer.is_human_readable() {
+            U22::from_u32(u32::deserialize(deserializer)?)
+        } else {
+            U22::from_le_bytes(<[u8; 3]>::deserialize(deserializer)?)
+        };
+        u22.map_err(D::Error::custom)
+    }
+}
+
+/// The human-readable shape of a [`PackedChar`], tagged by variant.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "PackedChar", rename_all = "lowercase")]
+enum Tagged {
+    Char(char),
+    U22(U22),
+}
+
+/// Tags a `char` in the compact shape of a [`PackedChar`].
+const CHAR_TAG: u8 = 0;
+/// Tags a [`U22`] in the compact shape of a [`PackedChar`].
+const U22_TAG: u8 = 1;
+
+/// Serializes as an externally tagged variant in human-readable formats, such as `{"char":"a"}`
+/// or `{"u22":42}` in JSON. Compact formats get a tuple of a tag byte, `0` for a `char` and `1`
+/// for a [`U22`], and the code point or integer value as three little-endian bytes.
+///
+/// Requires the `serde` feature.
+impl Serialize for PackedChar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            match self.contents() {
+                Contents::Char(c) => Tagged::Char(c),
+                Contents::U22(u22) => Tagged::U22(u22),
+            }
+            .serialize(serializer)
+        } else {
+            let (tag, value) = match self.contents() {
+                Contents::Char(c) => (CHAR_TAG, c as u32),
+                Contents::U22(u22) => (U22_TAG, u22.as_u32()),
+            };
+            let [a, b, c, _] = value.to_le_bytes();
+            (tag, [a, b, c]).serialize(serializer)
+        }
+    }
+}
+
+/// Deserializes the shape written by the [`Serialize`] implementation, rejecting unknown tags
+/// and out-of-range payloads.
+///
+/// Requires the `serde` feature.
+impl<'de> Deserialize<'de> for PackedChar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            return Ok(match Tagged::deserialize(deserializer)? {
+                Tagged::Char(c) => Self::from_char(c),
+                Tagged::U22(u22) => Self::from_u22(u22),
+            });
+        }
+        let (tag, [a, b, c]) = <(u8, [u8; 3])>::deserialize(deserializer)?;
+        let value = u32::from_le_bytes([a, b, c, 0]);
+        match tag {
+            CHAR_TAG => match char::from_u32(value) {
+                Some(c) => Ok(Self::from_char(c)),
+                None => Err(D::Error::invalid_value(
+                    Unexpected::Unsigned(value.into()),
+                    &"a Unicode scalar value",
+                )),
+            },
+            U22_TAG => U22::from_u32(value)
+                .map(Self::from_u22)
+                .map_err(D::Error::custom),
+            _ => Err(D::Error::invalid_value(
+                Unexpected::Unsigned(tag.into()),
+                &"a tag of 0 for a char or 1 for a U22",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u22_json_is_a_number() {
+        let u22 = U22::from_u32(0x12_3456).unwrap();
+        let json = serde_json::to_string(&u22).unwrap();
+        assert_eq!(json, "1193046");
+        assert_eq!(serde_json::from_str::<U22>(&json).unwrap(), u22);
+        let too_big = serde_json::to_string(&(U22::MAX + 1)).unwrap();
+        assert!(serde_json::from_str::<U22>(&too_big).is_err());
+    }
+
+    #[test]
+    fn u22_compact_is_three_bytes() {
+        let u22 = U22::from_u32(U22::MAX).unwrap();
+        let bytes = postcard::to_allocvec(&u22).unwrap();
+        assert_eq!(bytes, [0xFF, 0xFF, 0x3F]);
+        assert_eq!(postcard::from_bytes::<U22>(&bytes).unwrap(), u22);
+        assert!(postcard::from_bytes::<U22>(&[0, 0, 0x40]).is_err());
+    }
+
+    #[test]
+    fn packed_char_json_is_tagged() {
+        let c = PackedChar::from_char('a');
+        let u22 = PackedChar::try_from(42).unwrap();
+        assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"char":"a"}"#);
+        assert_eq!(serde_json::to_string(&u22).unwrap(), r#"{"u22":42}"#);
+        for packed in [c, u22] {
+            let json = serde_json::to_string(&packed).unwrap();
+            assert_eq!(serde_json::from_str::<PackedChar>(&json).unwrap(), packed);
+        }
+        assert!(serde_json::from_str::<PackedChar>(r#"{"u16":42}"#).is_err());
+        assert!(serde_json::from_str::<PackedChar>(r#"{"u22":4194304}"#).is_err());
+    }
+
+    #[test]
+    fn packed_char_compact_roundtrips() {
+        use crate::testing::{BOUNDARY_CHARS, BOUNDARY_U22S};
+
+        let chars = BOUNDARY_CHARS.map(PackedChar::from_char);
+        let u22s = BOUNDARY_U22S.map(PackedChar::from_u22);
+        for packed in chars.into_iter().chain(u22s) {
+            let bytes = postcard::to_allocvec(&packed).unwrap();
+            assert_eq!(postcard::from_bytes::<PackedChar>(&bytes).unwrap(), packed);
+        }
+        let c = postcard::to_allocvec(&PackedChar::from_char('🫠')).unwrap();
+        assert_eq!(c, [0, 0xE0, 0xFA, 0x01]);
+        let u22 = postcard::to_allocvec(&PackedChar::try_from(42).unwrap()).unwrap();
+        assert_eq!(u22, [1, 42, 0, 0]);
+        assert!(postcard::from_bytes::<PackedChar>(&[2, 42, 0, 0]).is_err());
+        assert!(postcard::from_bytes::<PackedChar>(&[0, 0x00, 0xD8, 0]).is_err());
+        assert!(postcard::from_bytes::<PackedChar>(&[1, 0, 0, 0x40]).is_err());
+    }
+
+    #[test]
+    fn packed_char_bincode_is_four_bytes() {
+        for packed in [
+            PackedChar::from_char(char::MAX),
+            PackedChar::try_from(42).unwrap(),
+        ] {
+            let bytes = bincode::serialize(&packed).unwrap();
+            assert_eq!(bytes.len(), 4);
+            assert_eq!(bincode::deserialize::<PackedChar>(&bytes).unwrap(), packed);
+        }
+        assert!(bincode::deserialize::<PackedChar>(&[7, 0, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{Contents, PackedChar, PackedCharFromBitsError, U22FromU32Error, U22};
+
+impl PackedChar {
+    /// Decodes a slice of raw 32-bit representations, validating each word as it is visited.
+    /// See [`PackedChar::try_from_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, PackedCharFromBitsError};
+    /// let words = [PackedChar::from('a').to_bits(), u32::MAX];
+    /// let mut decoded = PackedChar::decode_u32_slice(&words);
+    /// assert_eq!(decoded.next(), Some(Ok(PackedChar::from('a'))));
+    /// assert_eq!(decoded.next(), Some(Err(PackedCharFromBitsError(u32::MAX))));
+    /// assert_eq!(decoded.next(), None);
+    /// ```
+    pub fn decode_u32_slice(
+        src: &[u32],
+    ) -> impl Iterator<Item = Result<Self, PackedCharFromBitsError>> + '_ {
+        src.iter().map(|&bits| Self::try_from_bits(bits))
+    }
+
+    /// Checks that every word in a slice of raw 32-bit representations is valid, failing on the
+    /// first one that is not. The error holds the index and value of the offending word. See
+    /// [`PackedChar::is_valid_bits`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let words = [PackedChar::from('a').to_bits(), 0xD800, u32::MAX, 0x11_0000];
+    /// assert_eq!(PackedChar::validate_all(&words[..2]), Ok(()));
+    /// assert_eq!(PackedChar::validate_all(&words), Err((2, u32::MAX)));
+    /// ```
+    pub const fn validate_all(src: &[u32]) -> Result<(), (usize, u32)> {
+        let mut i = 0;
+        while i < src.len() {
+            if !Self::is_valid_bits(src[i]) {
+                return Err((i, src[i]));
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Iterates over the `char`s in a slice, skipping any [`U22`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let packed = [PackedChar::from('h'), PackedChar::try_from(42).unwrap(), PackedChar::from('i')];
+    /// assert!(PackedChar::chars_only(&packed).eq("hi".chars()));
+    /// ```
+    pub fn chars_only(slice: &[Self]) -> impl Iterator<Item = char> + '_ {
+        slice.iter().filter_map(|packed| match packed.contents() {
+            Contents::Char(c) => Some(c),
+            Contents::U22(_) => None,
+        })
+    }
+
+    /// Iterates over the [`U22`]s in a slice, skipping any `char`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, U22};
+    /// let packed = [PackedChar::from('h'), PackedChar::try_from(42).unwrap(), PackedChar::from('i')];
+    /// assert!(PackedChar::u22_only(&packed).eq([U22::from_u32(42).unwrap()]));
+    /// ```
+    pub fn u22_only(slice: &[Self]) -> impl Iterator<Item = U22> + '_ {
+        slice.iter().filter_map(|packed| match packed.contents() {
+            Contents::Char(_) => None,
+            Contents::U22(u22) => Some(u22),
+        })
+    }
+
+    /// Iterates over the `char`s in a slice, handling any [`U22`]s according to the given
+    /// policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, SentinelPolicy};
+    /// let packed = [PackedChar::from('h'), PackedChar::try_from(42).unwrap(), PackedChar::from('i')];
+    /// let decode = |policy| PackedChar::decode_with_policy(&packed, policy).collect::<String>();
+    /// assert_eq!(decode(SentinelPolicy::Skip), "hi");
+    /// assert_eq!(decode(SentinelPolicy::ReplaceWith('_')), "h_i");
+    /// assert_eq!(decode(SentinelPolicy::Stop), "h");
+    /// ```
+    pub fn decode_with_policy(
+        slice: &[Self],
+        on_sentinel: SentinelPolicy,
+    ) -> impl Iterator<Item = char> + '_ {
+        slice
+            .iter()
+            .map_while(move |packed| match (packed.contents(), on_sentinel) {
+                (Contents::Char(c), _) => Some(Some(c)),
+                (Contents::U22(_), SentinelPolicy::Skip) => Some(None),
+                (Contents::U22(_), SentinelPolicy::ReplaceWith(c)) => Some(Some(c)),
+                (Contents::U22(_), SentinelPolicy::Stop) => None,
+            })
+            .flatten()
+    }
+
+    /// Writes a slice to a byte stream. `char`s are written as UTF-8. Each [`U22`] is written
+    /// as `sentinel_byte` followed by its 3 bytes in little-endian order.
+    ///
+    /// For the output to be decodable, `sentinel_byte` should be a byte that never occurs in
+    /// UTF-8, such as `0xFF`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::PackedChar;
+    /// let packed = [PackedChar::from('h'), PackedChar::try_from(0x12_3456).unwrap()];
+    /// let mut out = Vec::new();
+    /// PackedChar::encode_stream(&packed, 0xFF, &mut out);
+    /// assert_eq!(out, [b'h', 0xFF, 0x56, 0x34, 0x12]);
+    /// ```
+    pub fn encode_stream(slice: &[Self], sentinel_byte: u8, out: &mut impl Extend<u8>) {
+        for packed in slice {
+            match packed.contents() {
+                Contents::Char(c) => {
+                    let mut buf = [0; 4];
+                    out.extend(c.encode_utf8(&mut buf).bytes());
+                }
+                Contents::U22(u22) => {
+                    out.extend([sentinel_byte]);
+                    out.extend(u22.to_le_bytes());
+                }
+            }
+        }
+    }
+}
+
+impl U22 {
+    /// Converts 32-bit integers from an iterator into the destination slice until either runs
+    /// out, returning the number of elements written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first element that exceeds [`U22::MAX`]. Elements before it have already
+    /// been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// let mut dst = [U22::default(); 4];
+    /// assert_eq!(U22::try_fill([1, 2, 3], &mut dst), Ok(3));
+    /// assert_eq!(dst[2].as_u32(), 3);
+    /// assert_eq!(U22::try_fill([7, u32::MAX], &mut dst), Err(U22FromU32Error(u32::MAX)));
+    /// assert_eq!(dst[0].as_u32(), 7);
+    /// ```
+    pub fn try_fill(
+        iter: impl IntoIterator<Item = u32>,
+        dst: &mut [Self],
+    ) -> Result<usize, U22FromU32Error> {
+        let mut written = 0;
+        for (slot, n) in dst.iter_mut().zip(iter) {
+            *slot = Self::from_u32(n)?;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+/// How [`PackedChar::decode_with_policy`] handles [`U22`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SentinelPolicy {
+    /// Omit the [`U22`] from the output.
+    Skip,
+    /// Output the given `char` in place of the [`U22`].
+    ReplaceWith(char),
+    /// End the output at the first [`U22`].
+    Stop,
+}
+
+/// Indexing slices by [`U22`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{SliceExt, U22};
+/// let slice = ['a', 'b', 'c'];
+/// assert_eq!(slice.get_u22(U22::from(1u8)), Some(&'b'));
+/// assert_eq!(slice.get_u22(U22::from(3u8)), None);
+/// assert_eq!(slice.index_u22(U22::from(2u8)), &'c');
+/// ```
+pub trait SliceExt<T> {
+    /// Returns a reference to the element at the given index, or `None` if it is out of bounds.
+    fn get_u22(&self, index: U22) -> Option<&T>;
+
+    /// Returns a reference to the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    fn index_u22(&self, index: U22) -> &T;
+}
+
+impl<T> SliceExt<T> for [T] {
+    fn get_u22(&self, index: U22) -> Option<&T> {
+        usize::try_from(index.as_u32())
+            .ok()
+            .and_then(|index| self.get(index))
+    }
+
+    fn index_u22(&self, index: U22) -> &T {
+        match self.get_u22(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
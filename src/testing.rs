@@ -0,0 +1,84 @@
+//! Helpers for testing code built on this crate.
+//!
+//! Requires the `testing` feature.
+
+use crate::{Contents, PackedChar, U22};
+
+/// `char`s at the edges of the ranges the encoding depends on.
+pub const BOUNDARY_CHARS: [char; 10] = [
+    '\0',
+    // Last char before the surrogate range
+    '\u{D7FF}',
+    // First char after the surrogate range
+    '\u{E000}',
+    // Char containing surrogate mask
+    '\u{1DFFF}',
+    // Char not containing surrogate mask
+    '\u{107FF}',
+    char::REPLACEMENT_CHARACTER,
+    char::MAX,
+    'a',
+    '1',
+    '🫠',
+];
+
+/// [`U22`] values at the edges of the 11-bit chunks the encoding splits them into.
+pub const BOUNDARY_U22S: [U22; 6] = {
+    const fn u22(n: u32) -> U22 {
+        match U22::from_u32(n) {
+            Ok(u22) => u22,
+            Err(_) => panic!("boundary value out of range"),
+        }
+    }
+    [
+        u22(0),
+        u22(0x7FF),
+        u22(0x800),
+        u22(42),
+        u22(0b1010101010101010101010),
+        u22(U22::MAX),
+    ]
+};
+
+impl PackedChar {
+    /// Whether packing and then unpacking the given contents gives them back unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{PackedChar, Contents, testing::BOUNDARY_CHARS};
+    /// for c in BOUNDARY_CHARS {
+    ///     assert!(PackedChar::roundtrip(Contents::Char(c)));
+    /// }
+    /// ```
+    pub fn roundtrip(contents: Contents) -> bool {
+        Self::from(contents).contents() == contents
+    }
+}
+
+impl Contents {
+    /// Interprets the same number as each variant: first as a `char` code point, then as a
+    /// [`U22`]. An interpretation is `None` if the number is not valid for that variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{Contents, U22};
+    /// let [c, u22] = Contents::variants_for(0x61);
+    /// assert_eq!(c, Some(Contents::Char('a')));
+    /// assert_eq!(u22, Some(Contents::U22(U22::from(0x61u8))));
+    /// assert_eq!(Contents::variants_for(0xD800)[0], None);
+    /// assert_eq!(Contents::variants_for(U22::MAX + 1), [None, None]);
+    /// ```
+    pub const fn variants_for(value: u32) -> [Option<Self>; 2] {
+        let c = match char::from_u32(value) {
+            Some(c) => Some(Self::Char(c)),
+            None => None,
+        };
+        let u22 = match U22::from_u32(value) {
+            Ok(u22) => Some(Self::U22(u22)),
+            Err(_) => None,
+        };
+        [c, u22]
+    }
+}
@@ -1,11 +1,42 @@
+use crate::SliceExt;
 use core::{
     borrow::Borrow,
-    fmt::{self, Display, Formatter},
-    ops::Deref,
+    fmt::{self, Debug, Display, Formatter},
+    num::NonZeroU32,
+    ops::{Add, Deref, Mul, Not, Sub},
 };
 
 /// A 22-bit unsigned integer.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// # `Deref` hazard
+///
+/// `U22` dereferences to its underlying `u32`, so any `u32` method without a `U22` counterpart
+/// can be called directly and operates on all 32 bits. For example, `reverse_bits` and
+/// `rotate_left` will move bits outside the 22-bit field and `checked_add` would happily exceed
+/// [`U22::MAX`] if `U22` did not shadow it. Methods whose results depend on the bit width, such as
+/// [`U22::leading_zeros`], are implemented on `U22` itself and take precedence over the `u32`
+/// versions. Call [`U22::as_u32`] to make 32-bit semantics explicit.
+///
+/// Code that called [`U22::leading_zeros`], [`U22::trailing_zeros`], [`U22::count_zeros`],
+/// [`U22::leading_ones`], or [`U22::trailing_ones`] before these methods existed got the `u32`
+/// versions through `Deref`. The same calls now count within the 22-bit field and compile without
+/// any warning, so `leading_zeros` and `count_zeros` are 10 smaller, `leading_ones` of
+/// [`U22::MAX`] is 22 rather than 0, and `trailing_zeros` of zero is 22 rather than 32.
+/// `trailing_ones` returns the same values as before.
+///
+/// ```
+/// # use packed_char::U22;
+/// let u22 = U22::from_u32(1).unwrap();
+/// assert_eq!(u22.leading_zeros(), 21);
+/// assert_eq!(u22.as_u32().leading_zeros(), 31);
+/// ```
+///
+/// # Layout
+///
+/// `U22` is `#[repr(transparent)]` over a `u32`, so it has the same size, alignment, and ABI as
+/// `u32`. The upper 10 bits are always zero.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct U22(u32);
 
 impl Display for U22 {
@@ -14,85 +45,1930 @@ impl Display for U22 {
     }
 }
 
+/// Formats the value in hexadecimal to set it apart from `char`s in mixed output.
+///
+/// ```
+/// # use packed_char::U22;
+/// assert_eq!(format!("{:?}", U22::from(42u8)), "U22(0x2a)");
+/// ```
+impl Debug for U22 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("U22")
+            .field(&format_args!("{:#x}", self.0))
+            .finish()
+    }
+}
+
 impl U22 {
     /// The largest value that can be expressed by this type
-    pub const MAX: u32 = !(u32::MAX << 22);
+    pub const MAX: u32 = !(u32::MAX << Self::BITS);
+
+    /// The size of this integer type in bits
+    pub const BITS: u32 = 22;
+
+    /// The bits of a `u32` that are occupied by a 22-bit integer
+    pub const MASK: u32 = Self::MAX;
+
+    /// The largest value that is also a `char` code point. Every `char` fits numerically in a
+    /// `U22`, so a `u32` up to this value could be read as either.
+    pub const CHAR_OVERLAP_MAX: u32 = char::MAX as u32;
+
+    /// Creates a new 22-bit integer from the given 32-bit integer if it is small enough to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// assert_eq!(U22::from_u32(42).map(U22::as_u32), Ok(42));
+    /// assert_eq!(U22::from_u32(U22::MAX).map(U22::as_u32), Ok(U22::MAX));
+    /// assert_eq!(U22::from_u32(U22::MAX + 1), Err(U22FromU32Error(U22::MAX + 1)));
+    /// ```
+    #[inline]
+    pub const fn from_u32(n: u32) -> Result<Self, U22FromU32Error> {
+        if n > Self::MAX {
+            Err(U22FromU32Error(n))
+        } else {
+            Ok(Self(n))
+        }
+    }
+
+    /// Creates a new 22-bit integer from the given 32-bit integer.
+    ///
+    /// # Safety
+    ///
+    /// The provided integer must be no greater than [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = unsafe { U22::from_u32_unchecked(42) };
+    /// assert_eq!(u22.as_u32(), 42);
+    /// ```
+    #[inline]
+    pub const unsafe fn from_u32_unchecked(n: u32) -> Self {
+        Self(n)
+    }
+
+    /// Creates a new 22-bit integer from the given 32-bit integer, clamping it to [`U22::MAX`]
+    /// if it is too large to fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32_saturating(42).as_u32(), 42);
+    /// assert_eq!(U22::from_u32_saturating(u32::MAX).as_u32(), U22::MAX);
+    /// ```
+    pub const fn from_u32_saturating(n: u32) -> Self {
+        if n > Self::MAX {
+            Self(Self::MAX)
+        } else {
+            Self(n)
+        }
+    }
+
+    /// Creates a new 22-bit integer from the low 22 bits of the given 32-bit integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32_wrapping(42).as_u32(), 42);
+    /// assert_eq!(U22::from_u32_wrapping(U22::MAX + 1).as_u32(), 0);
+    /// assert_eq!(U22::from_u32_wrapping(u32::MAX).as_u32(), U22::MAX);
+    /// ```
+    pub const fn from_u32_wrapping(n: u32) -> Self {
+        Self(n & Self::MASK)
+    }
+
+    /// Reinterprets a slice of 32-bit integers as 22-bit integers if every element is small
+    /// enough to fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first element that exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// let u22s = U22::from_u32_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(u22s[2].as_u32(), 3);
+    /// assert_eq!(U22::from_u32_slice(&[1, u32::MAX, 3]), Err(U22FromU32Error(u32::MAX)));
+    /// ```
+    pub const fn from_u32_slice(src: &[u32]) -> Result<&[Self], U22FromU32Error> {
+        let mut i = 0;
+        while i < src.len() {
+            if let Err(e) = Self::from_u32(src[i]) {
+                return Err(e);
+            }
+            i += 1;
+        }
+        // SAFETY: U22 is a transparent wrapper around u32 and every element is in range.
+        Ok(unsafe { core::slice::from_raw_parts(src.as_ptr().cast(), src.len()) })
+    }
+
+    /// Reinterprets a mutable slice of 32-bit integers as 22-bit integers if every element is
+    /// small enough to fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first element that exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let mut src = [1, 2, 3];
+    /// let u22s = U22::from_u32_slice_mut(&mut src).unwrap();
+    /// u22s[0] = U22::from_u32(42).unwrap();
+    /// assert_eq!(src, [42, 2, 3]);
+    /// ```
+    pub fn from_u32_slice_mut(src: &mut [u32]) -> Result<&mut [Self], U22FromU32Error> {
+        if let Some(&n) = src.iter().find(|&&n| n > Self::MAX) {
+            return Err(U22FromU32Error(n));
+        }
+        // SAFETY: U22 is a transparent wrapper around u32 and every element is in range.
+        Ok(unsafe { core::slice::from_raw_parts_mut(src.as_mut_ptr().cast(), src.len()) })
+    }
+
+    /// Converts an array of 32-bit integers to 22-bit integers if every element is small enough
+    /// to fit. Useful for building `const` lookup tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first element that exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// const TABLE: [U22; 3] = match U22::from_u32_array([1, 2, 3]) {
+    ///     Ok(table) => table,
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(TABLE[2].as_u32(), 3);
+    /// assert_eq!(U22::from_u32_array([1, u32::MAX]), Err(U22FromU32Error(u32::MAX)));
+    /// ```
+    pub const fn from_u32_array<const N: usize>(
+        src: [u32; N],
+    ) -> Result<[Self; N], U22FromU32Error> {
+        let mut out = [Self(0); N];
+        let mut i = 0;
+        while i < N {
+            match Self::from_u32(src[i]) {
+                Ok(u22) => out[i] = u22,
+                Err(e) => return Err(e),
+            }
+            i += 1;
+        }
+        Ok(out)
+    }
+
+    /// Gets the 22-bit integer as a 32-bit integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(42).unwrap();
+    /// assert_eq!(u22.as_ref(), &42);
+    /// ```
+    #[inline]
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Modifies the integer in place, keeping the result only if it is no greater than
+    /// [`U22::MAX`]. On error, the value is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// let mut u22 = U22::from_u32(21).unwrap();
+    /// assert_eq!(u22.modify(|n| n * 2), Ok(()));
+    /// assert_eq!(u22.as_u32(), 42);
+    /// assert_eq!(u22.modify(|_| u32::MAX), Err(U22FromU32Error(u32::MAX)));
+    /// assert_eq!(u22.as_u32(), 42);
+    /// ```
+    pub fn modify(&mut self, f: impl FnOnce(u32) -> u32) -> Result<(), U22FromU32Error> {
+        *self = Self::from_u32(f(self.0))?;
+        Ok(())
+    }
+
+    /// Returns the memory representation of this integer as a byte array in little-endian byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0x12_3456).unwrap();
+    /// assert_eq!(u22.to_le_bytes(), [0x56, 0x34, 0x12]);
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; 3] {
+        let [a, b, c, _] = self.0.to_le_bytes();
+        [a, b, c]
+    }
+
+    /// Returns the memory representation of this integer as a byte array in big-endian byte
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0x12_3456).unwrap();
+    /// assert_eq!(u22.to_be_bytes(), [0x12, 0x34, 0x56]);
+    /// ```
+    pub const fn to_be_bytes(self) -> [u8; 3] {
+        let [_, a, b, c] = self.0.to_be_bytes();
+        [a, b, c]
+    }
+
+    /// Returns the memory representation of this integer as a byte array in native byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0x12_3456).unwrap();
+    /// let expected = if cfg!(target_endian = "little") {
+    ///     [0x56, 0x34, 0x12]
+    /// } else {
+    ///     [0x12, 0x34, 0x56]
+    /// };
+    /// assert_eq!(u22.to_ne_bytes(), expected);
+    /// ```
+    pub const fn to_ne_bytes(self) -> [u8; 3] {
+        if cfg!(target_endian = "little") {
+            self.to_le_bytes()
+        } else {
+            self.to_be_bytes()
+        }
+    }
+
+    /// Creates a 22-bit integer from its representation as a byte array in little-endian byte
+    /// order if the top two bits are clear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// assert_eq!(U22::from_le_bytes([0x56, 0x34, 0x12]).map(U22::as_u32), Ok(0x12_3456));
+    /// assert_eq!(U22::from_le_bytes([0, 0, 0x40]), Err(U22FromU32Error(0x40_0000)));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 3]) -> Result<Self, U22FromU32Error> {
+        let [a, b, c] = bytes;
+        Self::from_u32(u32::from_le_bytes([a, b, c, 0]))
+    }
+
+    /// Creates a 22-bit integer from its representation as a byte array in big-endian byte
+    /// order if the top two bits are clear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// assert_eq!(U22::from_be_bytes([0x12, 0x34, 0x56]).map(U22::as_u32), Ok(0x12_3456));
+    /// assert_eq!(U22::from_be_bytes([0x40, 0, 0]), Err(U22FromU32Error(0x40_0000)));
+    /// ```
+    pub const fn from_be_bytes(bytes: [u8; 3]) -> Result<Self, U22FromU32Error> {
+        let [a, b, c] = bytes;
+        Self::from_u32(u32::from_be_bytes([0, a, b, c]))
+    }
+
+    /// Creates a 22-bit integer from its representation as a byte array in native byte order if
+    /// the top two bits are clear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0x12_3456).unwrap();
+    /// assert_eq!(U22::from_ne_bytes(u22.to_ne_bytes()), Ok(u22));
+    /// ```
+    pub const fn from_ne_bytes(bytes: [u8; 3]) -> Result<Self, U22FromU32Error> {
+        if cfg!(target_endian = "little") {
+            Self::from_le_bytes(bytes)
+        } else {
+            Self::from_be_bytes(bytes)
+        }
+    }
+
+    /// Checked multiplication by a 32-bit integer. Computes `self * rhs`, returning `None` if
+    /// the result exceeds [`U22::MAX`].
+    ///
+    /// The product is computed in 64 bits, so a large `rhs` cannot overflow before the range
+    /// check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(2).unwrap();
+    /// assert_eq!(u22.checked_mul_u32(21).map(U22::as_u32), Some(42));
+    /// assert_eq!(u22.checked_mul_u32(U22::MAX), None);
+    /// assert_eq!(u22.checked_mul_u32(u32::MAX / 2 + 1), None);
+    /// ```
+    pub const fn checked_mul_u32(self, rhs: u32) -> Option<Self> {
+        let product = self.0 as u64 * rhs as u64;
+        if product > Self::MAX as u64 {
+            None
+        } else {
+            Some(Self(product as u32))
+        }
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the result exceeds
+    /// [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(40).unwrap();
+    /// assert_eq!(u22.checked_add(U22::from(2u8)).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().checked_add(U22::from(1u8)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let sum = self.0 + rhs.0;
+        if sum > Self::MAX {
+            None
+        } else {
+            Some(Self(sum))
+        }
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if the result would be
+    /// negative.
+    ///
+    /// When `rhs <= self`, the result is the distance from `rhs` up to `self`, such as the
+    /// length of the span from `start` to `end`. Underflow never wraps around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(44).unwrap();
+    /// assert_eq!(u22.checked_sub(U22::from(2u8)).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(0u8).checked_sub(U22::from(1u8)), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(difference) => Some(Self(difference)),
+            None => None,
+        }
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if the result exceeds
+    /// [`U22::MAX`].
+    ///
+    /// The product is computed in 64 bits, so operands whose product overflows `u32` are
+    /// reported as `None` rather than wrapping back into range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(21).unwrap();
+    /// assert_eq!(u22.checked_mul(U22::from(2u8)).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().checked_mul(U22::from(2u8)), None);
+    /// let big = U22::from_u32(0x1F_FFFF).unwrap();
+    /// assert_eq!(big.checked_mul(big), None);
+    /// ```
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul_u32(rhs.0)
+    }
+
+    /// Computes `self + rhs`.
+    ///
+    /// Unlike the [`Add`] implementation, this is callable in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// # const fn u22(n: u32) -> U22 {
+    /// #     match U22::from_u32(n) {
+    /// #         Ok(u22) => u22,
+    /// #         Err(_) => panic!(),
+    /// #     }
+    /// # }
+    /// const SUM: U22 = u22(40).add(u22(2));
+    /// assert_eq!(SUM.as_u32(), 42);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub const fn add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(sum) => sum,
+            None => panic!("attempt to add with overflow"),
+        }
+    }
+
+    /// Computes `self - rhs`.
+    ///
+    /// Unlike the [`Sub`] implementation, this is callable in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// # const fn u22(n: u32) -> U22 {
+    /// #     match U22::from_u32(n) {
+    /// #         Ok(u22) => u22,
+    /// #         Err(_) => panic!(),
+    /// #     }
+    /// # }
+    /// const DIFFERENCE: U22 = u22(44).sub(u22(2));
+    /// assert_eq!(DIFFERENCE.as_u32(), 42);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub const fn sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(difference) => difference,
+            None => panic!("attempt to subtract with overflow"),
+        }
+    }
+
+    /// Computes `self * rhs`.
+    ///
+    /// Unlike the [`Mul`] implementation, this is callable in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// # const fn u22(n: u32) -> U22 {
+    /// #     match U22::from_u32(n) {
+    /// #         Ok(u22) => u22,
+    /// #         Err(_) => panic!(),
+    /// #     }
+    /// # }
+    /// const PRODUCT: U22 = u22(21).mul(u22(2));
+    /// assert_eq!(PRODUCT.as_u32(), 42);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => panic!("attempt to multiply with overflow"),
+        }
+    }
+
+    /// Returns the number of leading zeros within the 22-bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(0).unwrap().leading_zeros(), 22);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().leading_zeros(), 0);
+    /// ```
+    pub const fn leading_zeros(self) -> u32 {
+        self.0.leading_zeros() - (u32::BITS - Self::BITS)
+    }
+
+    /// Returns the number of trailing zeros within the 22-bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(0).unwrap().trailing_zeros(), 22);
+    /// assert_eq!(U22::from_u32(0b1000).unwrap().trailing_zeros(), 3);
+    /// ```
+    pub const fn trailing_zeros(self) -> u32 {
+        if self.0 == 0 {
+            Self::BITS
+        } else {
+            self.0.trailing_zeros()
+        }
+    }
+
+    /// Returns the number of zeros within the 22-bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(0).unwrap().count_zeros(), 22);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().count_zeros(), 0);
+    /// ```
+    pub const fn count_zeros(self) -> u32 {
+        Self::BITS - self.0.count_ones()
+    }
+
+    /// Panic-free bitwise shift-left. Computes `self << (rhs % 22)`, discarding any bits shifted
+    /// past the 22-bit field.
+    ///
+    /// Note that the shift amount is reduced modulo the 22-bit width rather than modulo 32 as
+    /// with [`u32::wrapping_shl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(1).unwrap();
+    /// assert_eq!(u22.wrapping_shl(21).as_u32(), 1 << 21);
+    /// assert_eq!(u22.wrapping_shl(22).as_u32(), 1);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().wrapping_shl(1).as_u32(), U22::MAX - 1);
+    /// ```
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        Self((self.0 << (rhs % Self::BITS)) & Self::MASK)
+    }
+
+    /// Panic-free bitwise shift-right. Computes `self >> (rhs % 22)`.
+    ///
+    /// Note that the shift amount is reduced modulo the 22-bit width rather than modulo 32 as
+    /// with [`u32::wrapping_shr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(1 << 21).unwrap();
+    /// assert_eq!(u22.wrapping_shr(21).as_u32(), 1);
+    /// assert_eq!(u22.wrapping_shr(22).as_u32(), 1 << 21);
+    /// ```
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        Self(self.0 >> (rhs % Self::BITS))
+    }
+
+    /// Whether the value is even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert!(U22::from(42u8).is_even());
+    /// assert!(!U22::from(41u8).is_even());
+    /// ```
+    pub const fn is_even(self) -> bool {
+        self.0 % 2 == 0
+    }
+
+    /// Whether the value is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert!(U22::from(41u8).is_odd());
+    /// assert!(!U22::from(42u8).is_odd());
+    /// ```
+    pub const fn is_odd(self) -> bool {
+        !self.is_even()
+    }
+
+    /// Whether `self` is an integer multiple of `rhs`.
+    ///
+    /// As with [`u32::is_multiple_of`], zero is considered a multiple of zero and no other value
+    /// is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert!(U22::from(42u8).is_multiple_of(U22::from(7u8)));
+    /// assert!(!U22::from(42u8).is_multiple_of(U22::from(5u8)));
+    /// assert!(U22::from(0u8).is_multiple_of(U22::from(0u8)));
+    /// assert!(!U22::from(42u8).is_multiple_of(U22::from(0u8)));
+    /// ```
+    pub const fn is_multiple_of(self, rhs: Self) -> bool {
+        match rhs.0 {
+            0 => self.0 == 0,
+            rhs => self.0 % rhs == 0,
+        }
+    }
+
+    /// Calculates the smallest value greater than or equal to `self` that is a multiple of
+    /// `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or if the result exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(40u8).next_multiple_of(U22::from(7u8)).as_u32(), 42);
+    /// assert_eq!(U22::from(42u8).next_multiple_of(U22::from(7u8)).as_u32(), 42);
+    /// ```
+    pub const fn next_multiple_of(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            panic!("attempt to calculate the next multiple of zero");
+        }
+        match self.checked_next_multiple_of(rhs) {
+            Some(multiple) => multiple,
+            None => panic!("attempt to calculate the next multiple with overflow"),
+        }
+    }
+
+    /// Calculates the smallest value greater than or equal to `self` that is a multiple of
+    /// `rhs`. Returns `None` if `rhs` is zero or if the result exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(40u8).checked_next_multiple_of(U22::from(7u8)).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(40u8).checked_next_multiple_of(U22::from(0u8)), None);
+    /// assert_eq!(max.checked_next_multiple_of(U22::from(2u8)), None);
+    /// assert_eq!(max.checked_next_multiple_of(max), Some(max));
+    /// ```
+    pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        // Cannot overflow a u32 since both operands are at most U22::MAX
+        match self.0.checked_next_multiple_of(rhs.0) {
+            Some(multiple) if multiple <= Self::MAX => Some(Self(multiple)),
+            _ => None,
+        }
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(125u8).ilog(U22::from(5u8)), 3);
+    /// ```
+    pub const fn ilog(self, base: Self) -> u32 {
+        self.0.ilog(base.0)
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().ilog2(), 21);
+    /// ```
+    pub const fn ilog2(self) -> u32 {
+        self.0.ilog2()
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(100u8).ilog10(), 2);
+    /// ```
+    pub const fn ilog10(self) -> u32 {
+        self.0.ilog10()
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down.
+    /// Returns `None` if `self` is zero or `base` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(125u8).checked_ilog(U22::from(5u8)), Some(3));
+    /// assert_eq!(U22::from(0u8).checked_ilog(U22::from(5u8)), None);
+    /// assert_eq!(U22::from(125u8).checked_ilog(U22::from(1u8)), None);
+    /// ```
+    pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+        self.0.checked_ilog(base.0)
+    }
+
+    /// Returns the base 2 logarithm of the number, rounded down. Returns `None` if `self` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(8u8).checked_ilog2(), Some(3));
+    /// assert_eq!(U22::from(0u8).checked_ilog2(), None);
+    /// ```
+    pub const fn checked_ilog2(self) -> Option<u32> {
+        self.0.checked_ilog2()
+    }
+
+    /// Returns the base 10 logarithm of the number, rounded down. Returns `None` if `self` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(100u8).checked_ilog10(), Some(2));
+    /// assert_eq!(U22::from(0u8).checked_ilog10(), None);
+    /// ```
+    pub const fn checked_ilog10(self) -> Option<u32> {
+        self.0.checked_ilog10()
+    }
+
+    /// Returns the number of leading ones within the 22-bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().leading_ones(), 22);
+    /// assert_eq!(U22::from_u32(0b11 << 20).unwrap().leading_ones(), 2);
+    /// assert_eq!(U22::from(1u8).leading_ones(), 0);
+    /// ```
+    pub const fn leading_ones(self) -> u32 {
+        (self.0 << (u32::BITS - Self::BITS)).leading_ones()
+    }
+
+    /// Returns the number of trailing ones within the 22-bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().trailing_ones(), 22);
+    /// assert_eq!(U22::from(0b0111u8).trailing_ones(), 3);
+    /// ```
+    pub const fn trailing_ones(self) -> u32 {
+        self.0.trailing_ones()
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the 22-bit boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(40u8).wrapping_add(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(max.wrapping_add(U22::from(2u8)).as_u32(), 1);
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) & Self::MASK)
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(40u8).saturating_add(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(max.saturating_add(U22::from(2u8)), max);
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_u32_saturating(self.0 + rhs.0)
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, saturating at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(44u8).saturating_sub(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(U22::from(1u8).saturating_sub(U22::from(2u8)).as_u32(), 0);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Saturating multiplication. Computes `self * rhs`, saturating at [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(21u8).saturating_mul(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(max.saturating_mul(max), max);
+    /// ```
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => Self(Self::MAX),
+        }
+    }
+
+    /// Fallible addition. Computes `self + rhs`, returning an error carrying the sum if it
+    /// exceeds [`U22::MAX`]. The sum of two `U22`s always fits in a `u32`, so it is never
+    /// truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(40u8).try_add(U22::from(2u8)).map(U22::as_u32), Ok(42));
+    /// assert_eq!(max.try_add(U22::from(1u8)), Err(U22FromU32Error(U22::MAX + 1)));
+    /// ```
+    pub const fn try_add(self, rhs: Self) -> Result<Self, U22FromU32Error> {
+        Self::from_u32(self.0 + rhs.0)
+    }
+
+    /// Fallible subtraction. Computes `self - rhs`, returning an error if `rhs` is larger.
+    ///
+    /// A `u32` cannot hold a negative difference, so the error carries it in two's complement,
+    /// as `self.as_u32().wrapping_sub(rhs.as_u32())`. This is always larger than [`U22::MAX`].
+    /// Cast it to `i32` to recover the negative difference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// assert_eq!(U22::from(44u8).try_sub(U22::from(2u8)).map(U22::as_u32), Ok(42));
+    /// let error = U22::from(0u8).try_sub(U22::from(1u8)).unwrap_err();
+    /// assert_eq!(error, U22FromU32Error(u32::MAX));
+    /// assert_eq!(error.0 as i32, -1);
+    /// ```
+    pub const fn try_sub(self, rhs: Self) -> Result<Self, U22FromU32Error> {
+        Self::from_u32(self.0.wrapping_sub(rhs.0))
+    }
+
+    /// Fallible multiplication. Computes `self * rhs`, returning an error carrying the product
+    /// if it exceeds [`U22::MAX`].
+    ///
+    /// The product is computed in 64 bits, so overflow is always detected. A product that does
+    /// not fit in a `u32` is reported as `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22FromU32Error};
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(21u8).try_mul(U22::from(2u8)).map(U22::as_u32), Ok(42));
+    /// assert_eq!(max.try_mul(U22::from(2u8)), Err(U22FromU32Error(U22::MAX * 2)));
+    /// assert_eq!(max.try_mul(max), Err(U22FromU32Error(u32::MAX)));
+    /// ```
+    pub const fn try_mul(self, rhs: Self) -> Result<Self, U22FromU32Error> {
+        let product = self.0 as u64 * rhs.0 as u64;
+        if product > u32::MAX as u64 {
+            Err(U22FromU32Error(u32::MAX))
+        } else {
+            Self::from_u32(product as u32)
+        }
+    }
+
+    /// Converts the value to the `char` with the same code point, if it is a valid Unicode
+    /// scalar value.
+    ///
+    /// This reinterprets the number itself and is unrelated to how [`PackedChar`](crate::PackedChar)
+    /// distinguishes its variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(0x61u8).to_char(), Some('a'));
+    /// assert_eq!(U22::from(0xD800u16).to_char(), None);
+    /// assert_eq!(U22::from_u32(0x11_0000).unwrap().to_char(), None);
+    /// ```
+    pub const fn to_char(self) -> Option<char> {
+        char::from_u32(self.0)
+    }
+
+    /// Creates a new 22-bit integer that is 1 for `true` and 0 for `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_bool(true).as_u32(), 1);
+    /// assert_eq!(U22::from_bool(false).as_u32(), 0);
+    /// ```
+    pub const fn from_bool(b: bool) -> Self {
+        Self(b as u32)
+    }
+
+    /// Converts 0 to `false` and 1 to `true`. Returns `None` for any other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(1u8).as_bool(), Some(true));
+    /// assert_eq!(U22::from(0u8).as_bool(), Some(false));
+    /// assert_eq!(U22::from(2u8).as_bool(), None);
+    /// ```
+    pub const fn as_bool(self) -> Option<bool> {
+        match self.0 {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Gets the 22-bit integer as a 64-bit integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(42u8).to_u64(), 42);
+    /// ```
+    pub const fn to_u64(self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Gets the 22-bit integer as a `usize`, for indexing in `const` contexts.
+    ///
+    /// Only available where `usize` is at least 32 bits wide. On 16-bit targets, use
+    /// `usize::try_from(u22.as_u32())` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// const TABLE: [u8; 3] = [1, 2, 3];
+    /// const ONE: U22 = U22::from_bool(true);
+    /// const SECOND: u8 = TABLE[ONE.as_usize()];
+    /// assert_eq!(SECOND, 2);
+    /// ```
+    #[cfg(not(target_pointer_width = "16"))]
+    pub const fn as_usize(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Concatenates two 22-bit integers into the low 44 bits of a 64-bit integer, with `self` in
+    /// the high half. Reversed by [`U22::unpack_two`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let (a, b) = (U22::from(1u8), U22::from(2u8));
+    /// assert_eq!(a.pack_with(b), 1 << 22 | 2);
+    /// assert_eq!(U22::unpack_two(a.pack_with(b)), (a, b));
+    /// ```
+    pub const fn pack_with(self, other: Self) -> u64 {
+        self.to_u64() << Self::BITS | other.to_u64()
+    }
+
+    /// Splits the low 44 bits of a 64-bit integer into two 22-bit integers, with the high half
+    /// first. Reverses [`U22::pack_with`]. Bits above the low 44 are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::unpack_two(1 << 22 | 2), (U22::from(1u8), U22::from(2u8)));
+    /// assert_eq!(U22::unpack_two(u64::MAX), (U22::from_u32(U22::MAX).unwrap(), U22::from_u32(U22::MAX).unwrap()));
+    /// ```
+    pub const fn unpack_two(n: u64) -> (Self, Self) {
+        let high = (n >> Self::BITS) as u32 & Self::MASK;
+        let low = n as u32 & Self::MASK;
+        (Self(high), Self(low))
+    }
+
+    /// Checked addition with a signed integer. Computes `self + delta`, returning `None` if the
+    /// result is negative or exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(44u8).checked_add_signed(-2).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(0u8).checked_add_signed(-1), None);
+    /// assert_eq!(U22::from(0u8).checked_add_signed(i32::MIN), None);
+    /// assert_eq!(U22::from(0u8).checked_add_signed(i32::MAX), None);
+    /// ```
+    pub const fn checked_add_signed(self, delta: i32) -> Option<Self> {
+        Self::from_i64(self.0 as i64 + delta as i64)
+    }
+
+    /// Checked subtraction of a signed integer. Computes `self - delta`, returning `None` if the
+    /// result is negative or exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(40u8).checked_sub_signed(-2).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(0u8).checked_sub_signed(1), None);
+    /// assert_eq!(U22::from(0u8).checked_sub_signed(i32::MIN), None);
+    /// ```
+    pub const fn checked_sub_signed(self, delta: i32) -> Option<Self> {
+        Self::from_i64(self.0 as i64 - delta as i64)
+    }
+
+    const fn from_i64(n: i64) -> Option<Self> {
+        if n < 0 || n > Self::MAX as i64 {
+            None
+        } else {
+            Some(Self(n as u32))
+        }
+    }
+
+    /// Euclidean division. For unsigned integers this is the same as ordinary division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(85u8).div_euclid(U22::from(2u8)).as_u32(), 42);
+    /// ```
+    pub const fn div_euclid(self, rhs: Self) -> Self {
+        Self(self.0.div_euclid(rhs.0))
+    }
+
+    /// Least remainder of Euclidean division. For unsigned integers this is the same as the
+    /// ordinary remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(85u8).rem_euclid(U22::from(2u8)).as_u32(), 1);
+    /// ```
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        Self(self.0.rem_euclid(rhs.0))
+    }
+
+    /// Checked Euclidean division. Returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(85u8).checked_div_euclid(U22::from(2u8)).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(85u8).checked_div_euclid(U22::from(0u8)), None);
+    /// ```
+    pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_div_euclid(rhs.0) {
+            Some(quotient) => Some(Self(quotient)),
+            None => None,
+        }
+    }
+
+    /// Checked Euclidean remainder. Returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(85u8).checked_rem_euclid(U22::from(2u8)).map(U22::as_u32), Some(1));
+    /// assert_eq!(U22::from(85u8).checked_rem_euclid(U22::from(0u8)), None);
+    /// ```
+    pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_rem_euclid(rhs.0) {
+            Some(remainder) => Some(Self(remainder)),
+            None => None,
+        }
+    }
+
+    /// Divides, rounding the quotient up. The result never exceeds `self`, so it always fits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let page = U22::from(10u8);
+    /// assert_eq!(U22::from(41u8).div_ceil(page).as_u32(), 5);
+    /// assert_eq!(U22::from(40u8).div_ceil(page).as_u32(), 4);
+    /// assert_eq!(U22::from(0u8).div_ceil(page).as_u32(), 0);
+    /// ```
+    pub const fn div_ceil(self, rhs: Self) -> Self {
+        Self(self.0.div_ceil(rhs.0))
+    }
+
+    /// Checked division rounding the quotient up. Returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(max.checked_div_ceil(U22::from(2u8)).map(U22::as_u32), Some(1 << 21));
+    /// assert_eq!(max.checked_div_ceil(U22::from(0u8)), None);
+    /// ```
+    pub const fn checked_div_ceil(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            None
+        } else {
+            Some(self.div_ceil(rhs))
+        }
+    }
+
+    /// Creates a new 22-bit integer from the given float, rounding to the nearest integer and
+    /// clamping to the range `0..=U22::MAX`. NaN converts to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from_f32_clamped(41.5).as_u32(), 42);
+    /// assert_eq!(U22::from_f32_clamped(-1.0).as_u32(), 0);
+    /// assert_eq!(U22::from_f32_clamped(f32::INFINITY).as_u32(), U22::MAX);
+    /// assert_eq!(U22::from_f32_clamped(f32::NAN).as_u32(), 0);
+    /// ```
+    pub const fn from_f32_clamped(x: f32) -> Self {
+        if x.is_nan() {
+            Self(0)
+        } else {
+            // Float to integer casts truncate and saturate, which clamps negative values to zero.
+            // Comparing the fractional part avoids the rounding error of adding 0.5 first.
+            let truncated = x as u32;
+            let rounded = if x - truncated as f32 >= 0.5 {
+                truncated.saturating_add(1)
+            } else {
+                truncated
+            };
+            Self::from_u32_saturating(rounded)
+        }
+    }
+
+    /// Converts the value to a float. This is exact since 22 bits fit in the significand of an
+    /// `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(42u8).to_f32(), 42.0);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().to_f32(), U22::MAX as f32);
+    /// ```
+    pub const fn to_f32(self) -> f32 {
+        self.0 as f32
+    }
+
+    /// Checked addition with a 32-bit integer. Computes `self + rhs`, returning `None` if the
+    /// result exceeds [`U22::MAX`].
+    ///
+    /// The sum is computed in 64 bits, so this never panics or wraps for a large `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(40u8).checked_add_u32(2).map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(10u8).checked_add_u32(u32::MAX), None);
+    /// ```
+    pub const fn checked_add_u32(self, rhs: u32) -> Option<Self> {
+        let sum = self.0 as u64 + rhs as u64;
+        if sum > Self::MAX as u64 {
+            None
+        } else {
+            Some(Self(sum as u32))
+        }
+    }
+
+    /// Increments the value in place. Returns `false` and leaves the value unchanged if it is
+    /// already [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let mut u22 = U22::from_u32(U22::MAX - 1).unwrap();
+    /// assert!(u22.inc());
+    /// assert!(!u22.inc());
+    /// assert_eq!(u22.as_u32(), U22::MAX);
+    /// ```
+    #[inline]
+    pub fn inc(&mut self) -> bool {
+        let moved = self.0 < Self::MAX;
+        self.0 += moved as u32;
+        moved
+    }
+
+    /// Decrements the value in place. Returns `false` and leaves the value unchanged if it is
+    /// already zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let mut u22 = U22::from(1u8);
+    /// assert!(u22.dec());
+    /// assert!(!u22.dec());
+    /// assert_eq!(u22.as_u32(), 0);
+    /// ```
+    #[inline]
+    pub fn dec(&mut self) -> bool {
+        let moved = self.0 > 0;
+        self.0 -= moved as u32;
+        moved
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the 22-bit boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(44u8).wrapping_sub(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(U22::from(0u8).wrapping_sub(U22::from(1u8)).as_u32(), U22::MAX);
+    /// ```
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0) & Self::MASK)
+    }
+
+    /// Wrapping multiplication. Computes `self * rhs`, wrapping around at the 22-bit boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(21u8).wrapping_mul(U22::from(2u8)).as_u32(), 42);
+    /// assert_eq!(max.wrapping_mul(max).as_u32(), 1);
+    /// ```
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_mul(rhs.0) & Self::MASK)
+    }
+
+    /// Sums a slice, returning `None` as soon as the running total exceeds [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::checked_sum(&[1u8, 2, 3].map(U22::from)), Some(U22::from(6u8)));
+    /// assert_eq!(U22::checked_sum(&[]), Some(U22::from(0u8)));
+    /// assert_eq!(U22::checked_sum(&[max, U22::from(1u8)]), None);
+    /// ```
+    pub const fn checked_sum(slice: &[Self]) -> Option<Self> {
+        let mut acc = Self(0);
+        let mut i = 0;
+        while i < slice.len() {
+            acc = match acc.checked_add(slice[i]) {
+                Some(sum) => sum,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(acc)
+    }
+
+    /// Sums a slice, wrapping around at the 22-bit boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::wrapping_sum(&[max, U22::from(3u8)]), U22::from(2u8));
+    /// ```
+    pub const fn wrapping_sum(slice: &[Self]) -> Self {
+        let mut acc = Self(0);
+        let mut i = 0;
+        while i < slice.len() {
+            acc = acc.wrapping_add(slice[i]);
+            i += 1;
+        }
+        acc
+    }
+
+    /// Multiplies a slice, returning `None` as soon as the running product exceeds
+    /// [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::checked_product(&[2u8, 3, 7].map(U22::from)), Some(U22::from(42u8)));
+    /// assert_eq!(U22::checked_product(&[]), Some(U22::from(1u8)));
+    /// assert_eq!(U22::checked_product(&[max, U22::from(2u8)]), None);
+    /// ```
+    pub const fn checked_product(slice: &[Self]) -> Option<Self> {
+        let mut acc = Self(1);
+        let mut i = 0;
+        while i < slice.len() {
+            acc = match acc.checked_mul(slice[i]) {
+                Some(product) => product,
+                None => return None,
+            };
+            i += 1;
+        }
+        Some(acc)
+    }
+
+    /// Whether the given integer is both a valid `char` and a valid `U22`, so it could be
+    /// misread as either variant. Surrogate code points are not ambiguous since they are not
+    /// valid `char`s.
+    ///
+    /// [`PackedChar::try_from`](crate::PackedChar) always stores a `u32` as a `U22`. Use
+    /// [`PackedChar::from_char`](crate::PackedChar::from_char) or
+    /// [`PackedChar::from_u22`](crate::PackedChar::from_u22) to be explicit about the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert!(U22::is_ambiguous_with_char('a' as u32));
+    /// assert!(U22::is_ambiguous_with_char(U22::CHAR_OVERLAP_MAX));
+    /// assert!(!U22::is_ambiguous_with_char(0xD800));
+    /// assert!(!U22::is_ambiguous_with_char(U22::CHAR_OVERLAP_MAX + 1));
+    /// ```
+    pub const fn is_ambiguous_with_char(n: u32) -> bool {
+        char::from_u32(n).is_some()
+    }
+
+    /// Iterates over every 22-bit integer in ascending order, from zero to [`U22::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let mut all = U22::all();
+    /// assert_eq!(all.len(), 1 << 22);
+    /// assert_eq!(all.next().map(U22::as_u32), Some(0));
+    /// assert_eq!(all.next_back().map(U22::as_u32), Some(U22::MAX));
+    /// ```
+    pub fn all() -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator {
+        (0..Self::MAX + 1).map(Self)
+    }
+
+    /// Computes the quotient and remainder of `self / rhs` together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let (row, column) = U22::from(23u8).div_rem(U22::from(10u8));
+    /// assert_eq!((row.as_u32(), column.as_u32()), (2, 3));
+    /// ```
+    pub const fn div_rem(self, rhs: Self) -> (Self, Self) {
+        (Self(self.0 / rhs.0), Self(self.0 % rhs.0))
+    }
+
+    /// Computes the quotient and remainder of `self / rhs` together. Returns `None` if `rhs` is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(23u8).checked_div_rem(U22::from(10u8)), Some((U22::from(2u8), U22::from(3u8))));
+    /// assert_eq!(U22::from(23u8).checked_div_rem(U22::from(0u8)), None);
+    /// ```
+    pub const fn checked_div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        if rhs.0 == 0 {
+            None
+        } else {
+            Some(self.div_rem(rhs))
+        }
+    }
+
+    /// Computes the greatest common divisor using Euclid's algorithm. The GCD of zero and zero
+    /// is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(12u8).gcd(U22::from(18u8)).as_u32(), 6);
+    /// assert_eq!(U22::from(0u8).gcd(U22::from(7u8)).as_u32(), 7);
+    /// assert_eq!(U22::from(0u8).gcd(U22::from(0u8)).as_u32(), 0);
+    /// ```
+    pub const fn gcd(self, other: Self) -> Self {
+        let (mut a, mut b) = (self.0, other.0);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        Self(a)
+    }
 
-    /// Creates a new 22-bit integer from the given 32-bit integer if it is small enough to fit.
+    /// Computes the least common multiple. Returns `None` if the result exceeds [`U22::MAX`].
+    /// The LCM of zero and any value is zero.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use packed_char::{U22, U22FromU32Error};
-    /// assert_eq!(U22::from_u32(42).map(U22::as_u32), Ok(42));
-    /// assert_eq!(U22::from_u32(U22::MAX).map(U22::as_u32), Ok(U22::MAX));
-    /// assert_eq!(U22::from_u32(U22::MAX + 1), Err(U22FromU32Error(U22::MAX + 1)));
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(4u8).lcm(U22::from(6u8)).map(U22::as_u32), Some(12));
+    /// assert_eq!(U22::from(0u8).lcm(U22::from(6u8)).map(U22::as_u32), Some(0));
+    /// assert_eq!(max.lcm(U22::from(2u8)), None);
     /// ```
-    pub const fn from_u32(n: u32) -> Result<Self, U22FromU32Error> {
-        if n > Self::MAX {
-            Err(U22FromU32Error(n))
+    pub const fn lcm(self, other: Self) -> Option<Self> {
+        if self.0 == 0 || other.0 == 0 {
+            return Some(Self(0));
+        }
+        let gcd = self.gcd(other);
+        Self(self.0 / gcd.0).checked_mul(other)
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if the result exceeds
+    /// [`U22::MAX`].
+    ///
+    /// Intermediate products are computed in 64 bits and checked at each step, so results
+    /// beyond the range of a `u32` are reported correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert_eq!(U22::from(2u8).checked_pow(21).map(U22::as_u32), Some(1 << 21));
+    /// assert_eq!(U22::from(2u8).checked_pow(22), None);
+    /// assert_eq!(U22::from(0u8).checked_pow(0).map(U22::as_u32), Some(1));
+    /// ```
+    pub const fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        const MAX: u64 = U22::MAX as u64;
+        if exp == 0 {
+            return Some(Self(1));
+        }
+        let mut base = self.0 as u64;
+        let mut acc = 1;
+        while exp > 1 {
+            if exp & 1 == 1 {
+                acc *= base;
+                if acc > MAX {
+                    return None;
+                }
+            }
+            exp /= 2;
+            base *= base;
+            if base > MAX {
+                return None;
+            }
+        }
+        acc *= base;
+        if acc > MAX {
+            None
         } else {
-            Ok(Self(n))
+            Some(Self(acc as u32))
         }
     }
 
-    /// Creates a new 22-bit integer from the given 32-bit integer.
+    /// Computes `2^exp`, returning `None` if `exp` is 22 or more, since the result would exceed
+    /// [`U22::MAX`].
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The provided integer must be no greater than [`U22::MAX`].
+    /// ```
+    /// # use packed_char::U22;
+    /// const BIT_5: Option<U22> = U22::pow2(5);
+    /// assert_eq!(BIT_5.map(U22::as_u32), Some(32));
+    /// assert_eq!(U22::pow2(21).map(U22::as_u32), Some(1 << 21));
+    /// assert_eq!(U22::pow2(22), None);
+    /// ```
+    pub const fn pow2(exp: u32) -> Option<Self> {
+        if exp < Self::BITS {
+            Some(Self(1 << exp))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the array element at this index, or `None` if it is out of
+    /// bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// # use packed_char::U22;
-    /// let u22 = unsafe { U22::from_u32_unchecked(42) };
-    /// assert_eq!(u22.as_u32(), 42);
+    /// const TABLE: [char; 3] = ['a', 'b', 'c'];
+    /// assert_eq!(U22::from(1u8).get_in(&TABLE), Some(&'b'));
+    /// assert_eq!(U22::from(3u8).get_in(&TABLE), None);
     /// ```
-    pub const unsafe fn from_u32_unchecked(n: u32) -> Self {
-        Self(n)
+    pub fn get_in<T, const N: usize>(self, arr: &[T; N]) -> Option<&T> {
+        arr.get_u22(self)
     }
 
-    /// Gets the 22-bit integer as a 32-bit integer.
+    /// Returns the number of significant bits, the minimum width needed to represent the
+    /// value. Zero needs no bits.
     ///
     /// # Examples
     ///
     /// ```
     /// # use packed_char::U22;
-    /// let u22 = U22::from_u32(42).unwrap();
-    /// assert_eq!(u22.as_ref(), &42);
+    /// assert_eq!(U22::from(0u8).bits(), 0);
+    /// assert_eq!(U22::from(5u8).bits(), 3);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().bits(), 22);
     /// ```
-    pub const fn as_u32(self) -> u32 {
-        self.0
+    pub const fn bits(self) -> u32 {
+        Self::BITS - self.leading_zeros()
+    }
+
+    /// Whether the value is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// assert!(U22::from(0u8).is_zero());
+    /// assert!(!U22::from(1u8).is_zero());
+    /// ```
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Classifies the value as zero, one, [`U22::MAX`], or any other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::{U22, U22Category};
+    /// assert_eq!(U22::from(0u8).classify(), U22Category::Zero);
+    /// assert_eq!(U22::from(1u8).classify(), U22Category::One);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().classify(), U22Category::Max);
+    /// assert_eq!(U22::from(42u8).classify(), U22Category::Other);
+    /// ```
+    pub const fn classify(self) -> U22Category {
+        match self.0 {
+            0 => U22Category::Zero,
+            1 => U22Category::One,
+            Self::MAX => U22Category::Max,
+            _ => U22Category::Other,
+        }
+    }
+
+    /// Appends a digit in the given base. Computes `self * base + digit`, returning `None` if
+    /// the result exceeds [`U22::MAX`], if `base` is not in `2..=36`, or if `digit` is not less
+    /// than `base`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let parsed = "42".chars().try_fold(U22::from(0u8), |acc, c| {
+    ///     acc.push_digit(c.to_digit(10)?, 10)
+    /// });
+    /// assert_eq!(parsed.map(U22::as_u32), Some(42));
+    /// assert_eq!(U22::from(4u8).push_digit(10, 10), None);
+    /// assert_eq!(U22::from(4u8).push_digit(1, 37), None);
+    /// assert_eq!(U22::from_u32(U22::MAX).unwrap().push_digit(0, 2), None);
+    /// ```
+    pub const fn push_digit(self, digit: u32, base: u32) -> Option<Self> {
+        if base < 2 || base > 36 || digit >= base {
+            return None;
+        }
+        match self.checked_mul_u32(base) {
+            Some(shifted) => shifted.checked_add_u32(digit),
+            None => None,
+        }
+    }
+
+    /// Spreads the 22 bits across all 32 bits of the result with the finalizer from MurmurHash3.
+    /// Useful for cheap hashing, such as probing an open-addressing table keyed by [`U22`].
+    ///
+    /// The mixing is deterministic and bijective, so distinct values never collide. It is not a
+    /// cryptographic hash and offers no protection against adversarial keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// const MIXED: u32 = U22::from_bool(true).mix();
+    /// assert_ne!(MIXED, 1);
+    /// assert_eq!(U22::from(0u8).mix(), 0);
+    /// assert_ne!(U22::from(1u8).mix() >> 22, U22::from(2u8).mix() >> 22);
+    /// ```
+    pub const fn mix(self) -> u32 {
+        let mut h = self.0;
+        h ^= h >> 16;
+        h = h.wrapping_mul(0x85EB_CA6B);
+        h ^= h >> 13;
+        h = h.wrapping_mul(0xC2B2_AE35);
+        h ^= h >> 16;
+        h
+    }
+
+    /// Computes the full product `self * rhs` without overflow, returning the low and high 22
+    /// bits of the 44-bit result, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// let (low, high) = max.widening_mul(max);
+    /// assert_eq!(low.as_u32(), 1);
+    /// assert_eq!(high.as_u32(), U22::MAX - 1);
+    /// let (low, high) = U22::from(21u8).widening_mul(U22::from(2u8));
+    /// assert_eq!((low.as_u32(), high.as_u32()), (42, 0));
+    /// ```
+    pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = self.0 as u64 * rhs.0 as u64;
+        let low = product as u32 & Self::MASK;
+        let high = (product >> Self::BITS) as u32;
+        (Self(low), Self(high))
+    }
+
+    /// Gets the `width` bits starting `offset` bits from the least significant end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + width` exceeds [`U22::BITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0b101_1100).unwrap();
+    /// assert_eq!(u22.extract_bits(2, 3).as_u32(), 0b111);
+    /// assert_eq!(u22.extract_bits(22, 0).as_u32(), 0);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use packed_char::U22;
+    /// U22::from(0u8).extract_bits(20, 3);
+    /// ```
+    pub const fn extract_bits(self, offset: u32, width: u32) -> Self {
+        let mask = Self::bit_range_mask(offset, width);
+        Self((self.0 >> offset) & mask)
+    }
+
+    /// Replaces the `width` bits starting `offset` bits from the least significant end with
+    /// `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + width` exceeds [`U22::BITS`] or if `value` does not fit in `width`
+    /// bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0b1_0000_0001).unwrap();
+    /// let updated = u22.insert_bits(2, 3, U22::from(0b101u8));
+    /// assert_eq!(updated.as_u32(), 0b1_0001_0101);
+    /// assert_eq!(updated.extract_bits(2, 3).as_u32(), 0b101);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use packed_char::U22;
+    /// U22::from(0u8).insert_bits(0, 2, U22::from(4u8));
+    /// ```
+    pub const fn insert_bits(self, offset: u32, width: u32, value: Self) -> Self {
+        let mask = Self::bit_range_mask(offset, width);
+        if value.0 & !mask != 0 {
+            panic!("value does not fit in the bit range");
+        }
+        Self(self.0 & !(mask << offset) | value.0 << offset)
+    }
+
+    const fn bit_range_mask(offset: u32, width: u32) -> u32 {
+        match offset.checked_add(width) {
+            Some(end) if end <= Self::BITS => (1 << width) - 1,
+            _ => panic!("bit range exceeds the 22 bits of a U22"),
+        }
+    }
+
+    /// Rounds down to the nearest multiple of `align`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let align = U22::from(64u8);
+    /// assert_eq!(U22::from(100u8).align_down(align).as_u32(), 64);
+    /// assert_eq!(U22::from(128u8).align_down(align).as_u32(), 128);
+    /// ```
+    pub const fn align_down(self, align: Self) -> Self {
+        if !align.0.is_power_of_two() {
+            panic!("align must be a power of two");
+        }
+        Self(self.0 & !(align.0 - 1))
+    }
+
+    /// Rounds up to the nearest multiple of `align`, returning `None` if the result exceeds
+    /// [`U22::MAX`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let align = U22::from(64u8);
+    /// let max = U22::from_u32(U22::MAX).unwrap();
+    /// assert_eq!(U22::from(100u8).align_up(align).map(U22::as_u32), Some(128));
+    /// assert_eq!(U22::from(128u8).align_up(align).map(U22::as_u32), Some(128));
+    /// assert_eq!(max.align_up(align), None);
+    /// assert_eq!(max.align_up(U22::from(1u8)), Some(max));
+    /// ```
+    pub const fn align_up(self, align: Self) -> Option<Self> {
+        if !align.0.is_power_of_two() {
+            panic!("align must be a power of two");
+        }
+        let mask = align.0 - 1;
+        let aligned = (self.0 + mask) & !mask;
+        if aligned > Self::MAX {
+            None
+        } else {
+            Some(Self(aligned))
+        }
+    }
+
+    /// Flips the low `width` bits and clears the bits above them. A `width` of 22 gives the full
+    /// complement, as does the [`Not`] implementation. A `width` of 0 gives zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` exceeds [`U22::BITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use packed_char::U22;
+    /// let u22 = U22::from_u32(0b1_0110).unwrap();
+    /// assert_eq!(u22.complement_within(4).as_u32(), 0b1001);
+    /// assert_eq!(u22.complement_within(0).as_u32(), 0);
+    /// assert_eq!(u22.complement_within(22), !u22);
+    /// ```
+    pub const fn complement_within(self, width: u32) -> Self {
+        if width > Self::BITS {
+            panic!("width exceeds the 22 bits of a U22");
+        }
+        Self(!self.0 & !(u32::MAX << width))
     }
 }
 
 impl TryFrom<u32> for U22 {
     type Error = U22FromU32Error;
 
+    #[inline]
     fn try_from(n: u32) -> Result<Self, Self::Error> {
         Self::from_u32(n)
     }
 }
 
+impl TryFrom<NonZeroU32> for U22 {
+    type Error = U22FromU32Error;
+
+    fn try_from(n: NonZeroU32) -> Result<Self, Self::Error> {
+        Self::from_u32(n.get())
+    }
+}
+
 impl From<U22> for u32 {
+    #[inline]
     fn from(u22: U22) -> Self {
         u22.0
     }
 }
 
+/// Only available where `usize` is at least 32 bits wide.
+#[cfg(not(target_pointer_width = "16"))]
+impl From<U22> for usize {
+    fn from(u22: U22) -> Self {
+        u22.as_usize()
+    }
+}
+
+impl From<bool> for U22 {
+    fn from(b: bool) -> Self {
+        Self::from_bool(b)
+    }
+}
+
+impl From<u8> for U22 {
+    fn from(n: u8) -> Self {
+        Self(n.into())
+    }
+}
+
+impl From<u16> for U22 {
+    fn from(n: u16) -> Self {
+        Self(n.into())
+    }
+}
+
+impl TryFrom<U22> for u8 {
+    type Error = TryFromU22Error;
+
+    fn try_from(u22: U22) -> Result<Self, Self::Error> {
+        u22.0.try_into().map_err(|_| TryFromU22Error(u22))
+    }
+}
+
+impl TryFrom<U22> for u16 {
+    type Error = TryFromU22Error;
+
+    fn try_from(u22: U22) -> Result<Self, Self::Error> {
+        u22.0.try_into().map_err(|_| TryFromU22Error(u22))
+    }
+}
+
+impl Add for U22 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds [`U22::MAX`].
+    fn add(self, rhs: Self) -> Self::Output {
+        U22::add(self, rhs)
+    }
+}
+
+impl Sub for U22 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be negative.
+    fn sub(self, rhs: Self) -> Self::Output {
+        U22::sub(self, rhs)
+    }
+}
+
+impl Mul for U22 {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result exceeds [`U22::MAX`].
+    fn mul(self, rhs: Self) -> Self::Output {
+        U22::mul(self, rhs)
+    }
+}
+
+/// Flips all 22 bits. See [`U22::complement_within`] for narrower fields.
+///
+/// ```
+/// # use packed_char::U22;
+/// assert_eq!((!U22::from(0u8)).as_u32(), U22::MAX);
+/// ```
+impl Not for U22 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement_within(Self::BITS)
+    }
+}
+
 impl AsRef<u32> for U22 {
     fn as_ref(&self) -> &u32 {
         &self.0
     }
 }
 
+/// `U22` hashes and compares exactly like its `u32` value, so maps keyed by `U22` can be
+/// queried with a `&u32`.
+///
+/// ```
+/// # use packed_char::U22;
+/// # use std::collections::HashMap;
+/// let mut map = HashMap::new();
+/// map.insert(U22::from(42u8), "answer");
+/// assert_eq!(map.get(&42u32), Some(&"answer"));
+/// ```
 impl Borrow<u32> for U22 {
     fn borrow(&self) -> &u32 {
         &self.0
     }
 }
 
+/// Only shared access to the underlying `u32` is provided. There is deliberately no `DerefMut`,
+/// `AsMut`, or `BorrowMut` implementation, since writing through them could exceed
+/// [`U22::MAX`]. Use [`U22::modify`] to make checked changes in place.
+///
+/// See the [`U22`] documentation for the hazards of calling `u32` methods through this
+/// implementation.
 impl Deref for U22 {
     type Target = u32;
 
@@ -101,6 +1977,19 @@ impl Deref for U22 {
     }
 }
 
+/// A classification of [`U22`] values, returned from [`U22::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum U22Category {
+    /// Zero
+    Zero,
+    /// One
+    One,
+    /// [`U22::MAX`]
+    Max,
+    /// Any other value
+    Other,
+}
+
 // TODO: Implement core::error::Error when stabilized
 
 /// Error type for 32-bit to 22-bit integer conversion.
@@ -115,3 +2004,246 @@ impl Display for U22FromU32Error {
         write!(f, "{} exceeds U22::MAX", self.0)
     }
 }
+
+/// Error type for 22-bit to narrower integer conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{TryFromU22Error, U22};
+/// let u22 = U22::from_u32(256).unwrap();
+/// assert_eq!(u8::try_from(u22), Err(TryFromU22Error(u22)));
+/// assert_eq!(u16::try_from(u22), Ok(256));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TryFromU22Error(
+    /// The [`U22`] that failed to be converted.
+    pub U22,
+);
+
+impl Display for TryFromU22Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in the target type", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn wrapping_shifts_reduce_modulo_width() {
+        let one = U22::from(1u8);
+        let top = U22::from_u32(1 << 21).unwrap();
+        assert_eq!(one.wrapping_shl(21), top);
+        assert_eq!(one.wrapping_shl(22), one);
+        assert_eq!(one.wrapping_shl(44), one);
+        assert_eq!(top.wrapping_shr(21), one);
+        assert_eq!(top.wrapping_shr(22), top);
+        assert_eq!(top.wrapping_shr(44), top);
+    }
+
+    #[test]
+    fn wrapping_shl_discards_high_bits() {
+        let max = U22::from_u32(U22::MAX).unwrap();
+        let top = U22::from_u32(1 << 21).unwrap();
+        assert_eq!(max.wrapping_shl(21), top);
+        assert_eq!(top.wrapping_shl(1), U22::default());
+    }
+
+    #[test]
+    fn mixed_arithmetic_boundaries() {
+        let max = U22::from_u32(U22::MAX).unwrap();
+        let ten = U22::from(10u8);
+        assert_eq!(max.checked_add_u32(0), Some(max));
+        assert_eq!(max.checked_add_u32(1), None);
+        assert_eq!(ten.checked_add_u32(U22::MAX - 10), Some(max));
+        assert_eq!(ten.checked_add_u32(U22::MAX - 9), None);
+        assert_eq!(ten.checked_add_u32(u32::MAX), None);
+        assert_eq!(max.checked_add_u32(u32::MAX), None);
+        assert_eq!(max.checked_mul_u32(1), Some(max));
+        assert_eq!(ten.checked_mul_u32(u32::MAX), None);
+        assert_eq!(max.checked_mul_u32(u32::MAX), None);
+    }
+
+    #[test]
+    fn checked_mul_detects_u32_overflow() {
+        let big = U22::from_u32(0x1F_FFFF).unwrap();
+        assert_eq!(big.checked_mul(big), None);
+        // 2^21 * 2^11 is 2^32, which wraps to 0 in u32 arithmetic.
+        let high = U22::from_u32(1 << 21).unwrap();
+        let low = U22::from_u32(1 << 11).unwrap();
+        assert_eq!(high.checked_mul(low), None);
+        assert_eq!(low.checked_mul(low), None);
+        let half = U22::from_u32(1 << 10).unwrap();
+        assert_eq!(low.checked_mul(half), Some(high));
+    }
+
+    #[test]
+    fn checked_sub_boundaries() {
+        let max = U22::from_u32(U22::MAX).unwrap();
+        let zero = U22::from(0u8);
+        let one = U22::from(1u8);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_sub(zero), Some(max));
+        assert_eq!(max.checked_sub(max), Some(zero));
+        assert_eq!(zero.checked_sub(max), None);
+    }
+
+    #[test]
+    fn align_up_near_max() {
+        let max = U22::from_u32(U22::MAX).unwrap();
+        let top = U22::from_u32(1 << 21).unwrap();
+        assert_eq!(top.align_up(top), Some(top));
+        assert_eq!(U22::from(1u8).align_up(top), Some(top));
+        assert_eq!(top.checked_add_u32(1).unwrap().align_up(top), None);
+        assert_eq!(max.align_down(top), top);
+        for exp in 0..U22::BITS {
+            let align = U22::pow2(exp).unwrap();
+            assert_eq!(max.align_up(align), (exp == 0).then_some(max));
+        }
+    }
+
+    #[test]
+    fn from_f32_clamped_rounds_like_round() {
+        for x in [
+            0.49999997f32,
+            0.5,
+            1.5,
+            2.4999998,
+            41.5,
+            4194302.5,
+            -0.4,
+            -0.6,
+        ] {
+            let expected = x.round().clamp(0.0, U22::MAX as f32) as u32;
+            assert_eq!(U22::from_f32_clamped(x).as_u32(), expected, "{x}");
+        }
+        assert_eq!(U22::from_f32_clamped(0.49999997).as_u32(), 0);
+        assert_eq!(U22::from_f32_clamped(1e30).as_u32(), U22::MAX);
+    }
+
+    #[test]
+    fn hash_matches_borrowed_u32() {
+        extern crate std;
+        use core::hash::BuildHasher;
+        use std::collections::{hash_map::RandomState, HashMap};
+
+        let state = RandomState::new();
+        for n in [0, 1, 42, 0x7FF, 0x800, U22::MAX] {
+            let u22 = U22::from_u32(n).unwrap();
+            assert_eq!(state.hash_one(u22), state.hash_one(n));
+            assert_eq!(
+                state.hash_one(u22),
+                state.hash_one(Borrow::<u32>::borrow(&u22))
+            );
+        }
+
+        let map: HashMap<_, _> = [0, 42, U22::MAX]
+            .into_iter()
+            .map(|n| (U22::from_u32(n).unwrap(), n))
+            .collect();
+        for n in [0, 42, U22::MAX] {
+            assert_eq!(map.get(&n), Some(&n));
+        }
+        assert_eq!(map.get(&1), None);
+    }
+
+    #[test]
+    fn checked_pow_overflow_boundaries() {
+        let pow = |base: u32, exp| {
+            U22::from_u32(base)
+                .unwrap()
+                .checked_pow(exp)
+                .map(U22::as_u32)
+        };
+        assert_eq!(pow(2047, 2), Some(2047 * 2047));
+        assert_eq!(pow(2048, 2), None);
+        assert_eq!(pow(161, 3), Some(161 * 161 * 161));
+        assert_eq!(pow(162, 3), None);
+        assert_eq!(pow(U22::MAX, 1), Some(U22::MAX));
+        assert_eq!(pow(U22::MAX, 2), None);
+        assert_eq!(pow(U22::MAX, 3), None);
+        assert_eq!(pow(U22::MAX, u32::MAX), None);
+        assert_eq!(pow(1, u32::MAX), Some(1));
+        assert_eq!(pow(0, u32::MAX), Some(0));
+        assert_eq!(pow(U22::MAX, 0), Some(1));
+    }
+
+    fn any_u22() -> impl Strategy<Value = U22> {
+        (0..=U22::MAX).prop_map(|n| U22::from_u32(n).unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn checked_pow_matches_reference(a in any_u22(), exp in 0..24u32) {
+            let expected = (a.as_u32() as u128)
+                .checked_pow(exp)
+                .filter(|&n| n <= U22::MAX as u128)
+                .map(|n| n as u32);
+            prop_assert_eq!(a.checked_pow(exp).map(U22::as_u32), expected);
+        }
+
+        #[test]
+        fn wrapping_add_matches_reference(a in any_u22(), b in any_u22()) {
+            let expected = (a.as_u32() as u64 + b.as_u32() as u64) % (1 << U22::BITS);
+            prop_assert_eq!(a.wrapping_add(b).as_u32() as u64, expected);
+        }
+
+        #[test]
+        fn saturating_add_matches_reference(a in any_u22(), b in any_u22()) {
+            let expected = (a.as_u32() + b.as_u32()).min(U22::MAX);
+            prop_assert_eq!(a.saturating_add(b).as_u32(), expected);
+        }
+
+        #[test]
+        fn saturating_sub_mul_match_reference(a in any_u22(), b in any_u22()) {
+            let difference = a.as_u32().saturating_sub(b.as_u32());
+            prop_assert_eq!(a.saturating_sub(b).as_u32(), difference);
+            let product = (a.as_u32() as u64 * b.as_u32() as u64).min(U22::MAX as u64);
+            prop_assert_eq!(a.saturating_mul(b).as_u32() as u64, product);
+        }
+
+        #[test]
+        fn mix_is_injective(a in any_u22(), b in any_u22()) {
+            prop_assert_eq!(a.mix() == b.mix(), a == b);
+        }
+
+        #[test]
+        fn widening_mul_matches_reference(a in any_u22(), b in any_u22()) {
+            let (low, high) = a.widening_mul(b);
+            prop_assert!(high.as_u32() <= U22::MAX);
+            let product = (high.as_u32() as u64) << U22::BITS | low.as_u32() as u64;
+            prop_assert_eq!(product, a.as_u32() as u64 * b.as_u32() as u64);
+        }
+
+        #[test]
+        fn try_ops_carry_true_result(a in any_u22(), b in any_u22()) {
+            let (x, y) = (a.as_u32(), b.as_u32());
+            let unwrap = |r: Result<U22, U22FromU32Error>| r.map_or_else(|e| e.0, U22::as_u32);
+            prop_assert_eq!(unwrap(a.try_add(b)), x + y);
+            prop_assert_eq!(unwrap(a.try_sub(b)) as i32 as i64, x as i64 - y as i64);
+            let product = x as u64 * y as u64;
+            prop_assert_eq!(unwrap(a.try_mul(b)) as u64, product.min(u32::MAX as u64));
+        }
+
+        #[test]
+        fn checked_add_matches_reference(a in any_u22(), b in any_u22()) {
+            let sum = a.as_u32() + b.as_u32();
+            let expected = (sum <= U22::MAX).then_some(sum);
+            prop_assert_eq!(a.checked_add(b).map(U22::as_u32), expected);
+        }
+
+        #[test]
+        fn add_families_agree(a in any_u22(), b in any_u22()) {
+            match a.checked_add(b) {
+                Some(sum) => {
+                    prop_assert_eq!(a.wrapping_add(b), sum);
+                    prop_assert_eq!(a.saturating_add(b), sum);
+                }
+                None => prop_assert_eq!(a.saturating_add(b).as_u32(), U22::MAX),
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::U22;
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, Deref, Mul, Sub},
+};
+
+/// A [`U22`] whose arithmetic operators wrap around at the 22-bit boundary.
+///
+/// The counterpart to [`core::num::Wrapping`].
+///
+/// # Examples
+///
+/// ```
+/// # use packed_char::{U22, WrappingU22};
+/// let max = WrappingU22(U22::from_u32(U22::MAX).unwrap());
+/// let one = WrappingU22(U22::from(1u8));
+/// assert_eq!((max + one).as_u32(), 0);
+/// assert_eq!((WrappingU22::default() - one).as_u32(), U22::MAX);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WrappingU22(pub U22);
+
+impl Display for WrappingU22 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for WrappingU22 {
+    type Target = U22;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<U22> for WrappingU22 {
+    fn from(u22: U22) -> Self {
+        Self(u22)
+    }
+}
+
+impl Add for WrappingU22 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl Sub for WrappingU22 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl Mul for WrappingU22 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0.wrapping_mul(rhs.0))
+    }
+}